@@ -0,0 +1,76 @@
+use options_pricing_rusty::{OptionType, OptionPricingModel, BlackScholesModel, HestonModel, FourierEngine, FourierMethod, FourierError};
+
+fn main() -> Result<(), FourierError> {
+
+    let underlying = 120.0;
+    let maturity = 1.0;
+    let volatility = 0.2;
+    let risk_free_rate = 0.05;
+
+    let strikes = [80.0, 90.0, 100.0, 110.0, 120.0, 130.0, 140.0, 150.0, 160.0];
+    let option_types = [OptionType::Call, OptionType::Put];
+
+    let header = "=".repeat(50);
+    let mid = "-".repeat(50);
+
+    let black_scholes_model = BlackScholesModel {
+        underlying,
+        strike: underlying,
+        maturity,
+        volatility,
+        risk_free_rate,
+    };
+
+    let carr_madan_engine = FourierEngine {
+        maturity,
+        risk_free_rate,
+        method: FourierMethod::CARR_MADAN,
+    };
+
+    let cos_engine = FourierEngine {
+        method: FourierMethod::COS,
+        ..carr_madan_engine.clone()
+    };
+
+    print!("\n{}\nModel: Black Scholes (Carr-Madan FFT / COS)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let carr_madan_prices = carr_madan_engine.price_strikes(&black_scholes_model, &strikes, *option)?;
+        let cos_prices = cos_engine.price_strikes(&black_scholes_model, &strikes, *option)?;
+
+        println!("Option: {:?}", option);
+        for (i, strike) in strikes.iter().enumerate() {
+            let exact = BlackScholesModel { strike: *strike, ..black_scholes_model.clone() }.price(*option);
+            println!("  Strike: {:.1}, Exact: {:.6}, FFT: {:.6} ({:+.1e}), COS: {:.6} ({:+.1e})",
+                     strike, exact, carr_madan_prices[i], carr_madan_prices[i] - exact, cos_prices[i], cos_prices[i] - exact);
+        }
+    }
+
+    let heston_model = HestonModel {
+        underlying,
+        strike: underlying,
+        maturity,
+        risk_free_rate,
+        initial_variance: 0.04,
+        mean_reversion: 2.0,
+        long_run_variance: 0.04,
+        vol_of_vol: 0.3,
+        correlation: -0.7,
+    };
+
+    print!("\n{}\nModel: Heston (Carr-Madan FFT / COS)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let carr_madan_prices = carr_madan_engine.price_strikes(&heston_model, &strikes, *option)?;
+        let cos_prices = cos_engine.price_strikes(&heston_model, &strikes, *option)?;
+
+        println!("Option: {:?}", option);
+        for (i, strike) in strikes.iter().enumerate() {
+            let integrated = HestonModel { strike: *strike, ..heston_model.clone() }.price(*option);
+            println!("  Strike: {:.1}, Integrated: {:.6}, FFT: {:.6}, COS: {:.6}",
+                     strike, integrated, carr_madan_prices[i], cos_prices[i]);
+        }
+    }
+
+    Ok(())
+}
\ No newline at end of file
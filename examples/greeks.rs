@@ -1,4 +1,4 @@
-use options_pricing_rusty::{OptionType, OptionPricingModel, BaseModel, BlackScholesModel, BinomialModel, MonteCarloModel};
+use options_pricing_rusty::{OptionType, OptionPricingModel, BaseModel, BlackScholesModel, BinomialModel, BinomialTree, ConvergenceAcceleration, MonteCarloModel, MertonJumpDiffusionModel, HestonModel, SabrModel, SabrVolatilityFormula, CevModel, VarianceGammaModel, NormalInverseGaussianModel, FiniteDifferenceModel, FiniteDifferenceScheme, EarlyExerciseMethod, ExerciseStyle, Payoff, TrinomialModel, TrinomialTree, AmericanApproximationModel, AmericanApproximation, LeastSquaresMonteCarloModel, BasisFunctions, MultiAssetMonteCarloModel, MultiAssetPayoff, BasketModel, BasketApproximation, ExchangeOptionModel, SpreadOptionModel, SpreadApproximation, QuantoModel, FxSettlement, CompoundOptionModel, ChooserOptionModel, ChooserStyle, ForwardStartModel, PowerOptionModel, GapOptionModel, DoubleBarrierModel, BarrierKnock, ParisianOptionModel, ParisianBarrier, ParisianMethod};
 
 fn main() {
 
@@ -8,6 +8,29 @@ fn main() {
     let volatility = 0.2; 
     let risk_free_rate = 0.05;   
     let steps = 252;
+    let jump_intensity = 0.75;
+    let jump_mean = -0.1;
+    let jump_volatility = 0.15;
+    let initial_variance = 0.04;
+    let mean_reversion = 2.0;
+    let long_run_variance = 0.04;
+    let vol_of_vol = 0.3;
+    let correlation = -0.7;
+    let alpha = 2.2;
+    let beta = 0.5;
+    let elasticity = 0.5;
+    let cev_volatility = volatility * f64::powf(underlying, 1.0 - elasticity);
+    let variance_rate = 0.2;
+    let drift = -0.14;
+    let nig_alpha = 8.0;
+    let nig_beta = -3.0;
+    let nig_scale = 0.3;
+    let price_steps = 400;
+    let stretch = f64::sqrt(1.5);
+    let dividend_yield = 0.02;
+    let foreign_rate = 0.03;
+    let fx_volatility = 0.1;
+    let fx_correlation = 0.3;
     let simulations = 50000;
 
     let option_types = [OptionType::Call, OptionType::Put];
@@ -70,6 +93,10 @@ fn main() {
         volatility,
         risk_free_rate,
         steps,
+        tree: BinomialTree::CoxRossRubinstein,
+        exercise: ExerciseStyle::European,
+        acceleration: ConvergenceAcceleration::None,
+        payoff: Payoff::Vanilla,
     };
 
     print!("\n{}\nModel: Binomial\n{}\n", header, mid);
@@ -88,15 +115,7 @@ fn main() {
     }
 
 
-    let monte_carlo_model = MonteCarloModel {
-        underlying,
-        strike,
-        maturity,
-        volatility,
-        risk_free_rate,
-        simulations,
-        steps,
-    };
+    let monte_carlo_model = MonteCarloModel::new(underlying, strike, maturity, volatility, risk_free_rate, simulations, steps);
 
     print!("\n{}\nModel: Monte Carlo\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
     
@@ -112,4 +131,634 @@ fn main() {
         println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
                 delta, gamma, theta, vega, rho);
     }
-}
+
+    let merton_model = MertonJumpDiffusionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        jump_intensity,
+        jump_mean,
+        jump_volatility,
+    };
+
+    print!("\n{}\nModel: Merton Jump Diffusion\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = merton_model.delta(*option);
+        let gamma = merton_model.gamma(*option);
+        let theta = merton_model.theta(*option);
+        let vega = merton_model.vega(*option);
+        let rho = merton_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let heston_model = HestonModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        initial_variance,
+        mean_reversion,
+        long_run_variance,
+        vol_of_vol,
+        correlation,
+    };
+
+    print!("\n{}\nModel: Heston\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = heston_model.delta(*option);
+        let gamma = heston_model.gamma(*option);
+        let theta = heston_model.theta(*option);
+        let vega = heston_model.vega(*option);
+        let rho = heston_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let sabr_model = SabrModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        alpha,
+        beta,
+        correlation,
+        vol_of_vol,
+        formula: SabrVolatilityFormula::Obloj,
+    };
+
+    print!("\n{}\nModel: SABR\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = sabr_model.delta(*option);
+        let gamma = sabr_model.gamma(*option);
+        let theta = sabr_model.theta(*option);
+        let vega = sabr_model.vega(*option);
+        let rho = sabr_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let cev_model = CevModel {
+        underlying,
+        strike,
+        maturity,
+        volatility: cev_volatility,
+        risk_free_rate,
+        elasticity,
+    };
+
+    print!("\n{}\nModel: CEV\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = cev_model.delta(*option);
+        let gamma = cev_model.gamma(*option);
+        let theta = cev_model.theta(*option);
+        let vega = cev_model.vega(*option);
+        let rho = cev_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let variance_gamma_model = VarianceGammaModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        volatility,
+        variance_rate,
+        drift,
+    };
+
+    print!("\n{}\nModel: Variance Gamma\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = variance_gamma_model.delta(*option);
+        let gamma = variance_gamma_model.gamma(*option);
+        let theta = variance_gamma_model.theta(*option);
+        let vega = variance_gamma_model.vega(*option);
+        let rho = variance_gamma_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let nig_model = NormalInverseGaussianModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        alpha: nig_alpha,
+        beta: nig_beta,
+        scale: nig_scale,
+    };
+
+    print!("\n{}\nModel: Normal Inverse Gaussian\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = nig_model.delta(*option);
+        let gamma = nig_model.gamma(*option);
+        let theta = nig_model.theta(*option);
+        let vega = nig_model.vega(*option);
+        let rho = nig_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let finite_difference_model = FiniteDifferenceModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        price_steps,
+        time_steps: steps as usize,
+        scheme: FiniteDifferenceScheme::CrankNicolson { rannacher_steps: 2 },
+        exercise: ExerciseStyle::European,
+        early_exercise: EarlyExerciseMethod::Penalty,
+        payoff: Payoff::Vanilla,
+    };
+
+    print!("\n{}\nModel: Finite Difference (Crank-Nicolson)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = finite_difference_model.delta(*option);
+        let gamma = finite_difference_model.gamma(*option);
+        let theta = finite_difference_model.theta(*option);
+        let vega = finite_difference_model.vega(*option);
+        let rho = finite_difference_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let trinomial_model = TrinomialModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        steps,
+        stretch,
+        tree: TrinomialTree::KamradRitchken,
+        exercise: ExerciseStyle::European,
+        payoff: Payoff::Vanilla,
+    };
+
+    print!("\n{}\nModel: Trinomial (Kamrad-Ritchken)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = trinomial_model.delta(*option);
+        let gamma = trinomial_model.gamma(*option);
+        let theta = trinomial_model.theta(*option);
+        let vega = trinomial_model.vega(*option);
+        let rho = trinomial_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let american_approximation_model = AmericanApproximationModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        method: AmericanApproximation::JuZhong,
+    };
+
+    print!("\n{}\nModel: American Approximation (Ju-Zhong)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = american_approximation_model.delta(*option);
+        let gamma = american_approximation_model.gamma(*option);
+        let theta = american_approximation_model.theta(*option);
+        let vega = american_approximation_model.vega(*option);
+        let rho = american_approximation_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let lsm_model = LeastSquaresMonteCarloModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        simulations,
+        exercise_dates: 50,
+        basis: BasisFunctions::Laguerre,
+        basis_degree: 3,
+        seed: 42,
+    };
+
+    print!("\n{}\nModel: Least-Squares Monte Carlo (American, Laguerre)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = lsm_model.delta(*option);
+        let gamma = lsm_model.gamma(*option);
+        let theta = lsm_model.theta(*option);
+        let vega = lsm_model.vega(*option);
+        let rho = lsm_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let multi_asset_model = MultiAssetMonteCarloModel {
+        underlyings: vec![underlying, underlying],
+        volatilities: vec![volatility, 0.3],
+        dividend_yields: vec![0.0, dividend_yield],
+        correlation: vec![vec![1.0, 0.5], vec![0.5, 1.0]],
+        strike,
+        maturity,
+        risk_free_rate,
+        simulations,
+        payoff: MultiAssetPayoff::Basket { weights: vec![0.5, 0.5] },
+        seed: 42,
+    };
+
+    print!("\n{}\nModel: Multi-Asset Monte Carlo (Equal-Weighted Basket of Two)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = multi_asset_model.delta(*option);
+        let gamma = multi_asset_model.gamma(*option);
+        let theta = multi_asset_model.theta(*option);
+        let vega = multi_asset_model.vega(*option);
+        let rho = multi_asset_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let basket_model = BasketModel {
+        underlyings: vec![underlying, underlying],
+        weights: vec![0.5, 0.5],
+        volatilities: vec![volatility, 0.3],
+        dividend_yields: vec![0.0, dividend_yield],
+        correlation: vec![vec![1.0, 0.5], vec![0.5, 1.0]],
+        strike,
+        maturity,
+        risk_free_rate,
+        method: BasketApproximation::Ju,
+    };
+
+    print!("\n{}\nModel: Basket (Ju, Equal-Weighted Basket of Two)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = basket_model.delta(*option);
+        let gamma = basket_model.gamma(*option);
+        let theta = basket_model.theta(*option);
+        let vega = basket_model.vega(*option);
+        let rho = basket_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let exchange_model = ExchangeOptionModel {
+        underlying1: underlying,
+        underlying2: strike,
+        maturity,
+        volatility1: volatility,
+        volatility2: 0.3,
+        dividend_yield1: 0.0,
+        dividend_yield2: dividend_yield,
+        correlation: 0.5,
+        risk_free_rate,
+    };
+
+    print!("\n{}\nModel: Exchange Option (Margrabe)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = exchange_model.delta(*option);
+        let gamma = exchange_model.gamma(*option);
+        let theta = exchange_model.theta(*option);
+        let vega = exchange_model.vega(*option);
+        let rho = exchange_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let spread_model = SpreadOptionModel {
+        underlying1: underlying,
+        underlying2: strike,
+        strike: 5.0,
+        maturity,
+        volatility1: volatility,
+        volatility2: 0.3,
+        dividend_yield1: 0.0,
+        dividend_yield2: dividend_yield,
+        correlation: 0.5,
+        risk_free_rate,
+        method: SpreadApproximation::BjerksundStensland,
+    };
+
+    print!("\n{}\nModel: Spread Option (Bjerksund-Stensland)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = spread_model.delta(*option);
+        let gamma = spread_model.gamma(*option);
+        let theta = spread_model.theta(*option);
+        let vega = spread_model.vega(*option);
+        let rho = spread_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let quanto_model = QuantoModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        foreign_rate,
+        dividend_yield,
+        fx_volatility,
+        fx_correlation,
+        settlement: FxSettlement::Quanto { fixed_rate: 1.0 },
+    };
+
+    print!("\n{}\nModel: Quanto\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = quanto_model.delta(*option);
+        let gamma = quanto_model.gamma(*option);
+        let theta = quanto_model.theta(*option);
+        let vega = quanto_model.vega(*option);
+        let rho = quanto_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let composite_model = QuantoModel {
+        strike: strike * 1.2,
+        settlement: FxSettlement::Composite { spot_rate: 1.2 },
+        ..quanto_model.clone()
+    };
+
+    print!("\n{}\nModel: Composite\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = composite_model.delta(*option);
+        let gamma = composite_model.gamma(*option);
+        let theta = composite_model.theta(*option);
+        let vega = composite_model.vega(*option);
+        let rho = composite_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let compound_model = CompoundOptionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        compound_strike: 10.0,
+        compound_maturity: 0.5 * maturity,
+        underlying_option: OptionType::Call,
+    };
+
+    print!("\n{}\nModel: Compound (On a Call)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = compound_model.delta(*option);
+        let gamma = compound_model.gamma(*option);
+        let theta = compound_model.theta(*option);
+        let vega = compound_model.vega(*option);
+        let rho = compound_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let chooser_model = ChooserOptionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        choice_time: 0.5 * maturity,
+        style: ChooserStyle::Simple,
+    };
+
+    print!("\n{}\nModel: Chooser (Simple)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = chooser_model.delta(*option);
+        let gamma = chooser_model.gamma(*option);
+        let theta = chooser_model.theta(*option);
+        let vega = chooser_model.vega(*option);
+        let rho = chooser_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let forward_start_model = ForwardStartModel {
+        underlying,
+        strike_ratio: 1.0,
+        start_time: 0.5 * maturity,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+    };
+
+    print!("\n{}\nModel: Forward Start\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = forward_start_model.delta(*option);
+        let gamma = forward_start_model.gamma(*option);
+        let theta = forward_start_model.theta(*option);
+        let vega = forward_start_model.vega(*option);
+        let rho = forward_start_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let power_model = PowerOptionModel {
+        underlying,
+        strike: strike.powi(2),
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        exponent: 2.0,
+    };
+
+    print!("\n{}\nModel: Power (Squared)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = power_model.delta(*option);
+        let gamma = power_model.gamma(*option);
+        let theta = power_model.theta(*option);
+        let vega = power_model.vega(*option);
+        let rho = power_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let gap_model = GapOptionModel {
+        underlying,
+        strike,
+        trigger_strike: 1.05 * strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+    };
+
+    print!("\n{}\nModel: Gap\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = gap_model.delta(*option);
+        let gamma = gap_model.gamma(*option);
+        let theta = gap_model.theta(*option);
+        let vega = gap_model.vega(*option);
+        let rho = gap_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let double_barrier_model = DoubleBarrierModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        lower_barrier: 0.8 * strike,
+        upper_barrier: 1.6 * strike,
+        knock: BarrierKnock::Out,
+    };
+
+    print!("\n{}\nModel: Double Barrier (Ikeda-Kunitomo, Knock-Out)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = double_barrier_model.delta(*option);
+        let gamma = double_barrier_model.gamma(*option);
+        let theta = double_barrier_model.theta(*option);
+        let vega = double_barrier_model.vega(*option);
+        let rho = double_barrier_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let parisian_model = ParisianOptionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        barrier: ParisianBarrier {
+            lower_barrier: 0.0,
+            upper_barrier: 1.5 * strike,
+            window: 0.1 * maturity,
+            knock: BarrierKnock::Out,
+        },
+        method: ParisianMethod::FiniteDifference { price_steps: 400, time_steps: 500 },
+    };
+
+    print!("\n{}\nModel: Parisian Up-and-Out (Finite Difference)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = parisian_model.delta(*option);
+        let gamma = parisian_model.gamma(*option);
+        let theta = parisian_model.theta(*option);
+        let vega = parisian_model.vega(*option);
+        let rho = parisian_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+
+    let parisian_monte_carlo_model = ParisianOptionModel {
+        method: ParisianMethod::MonteCarlo { simulations, steps, seed: 42 },
+        ..parisian_model.clone()
+    };
+
+    print!("\n{}\nModel: Parisian Up-and-Out (Monte Carlo)\n{}\n", header, mid);
+
+    for option in &option_types {
+        print!("Option: {:?}", option);
+
+        let delta = parisian_monte_carlo_model.delta(*option);
+        let gamma = parisian_monte_carlo_model.gamma(*option);
+        let theta = parisian_monte_carlo_model.theta(*option);
+        let vega = parisian_monte_carlo_model.vega(*option);
+        let rho = parisian_monte_carlo_model.rho(*option);
+
+        println!("\n  Delta: {:.4}\n  Gamma: {:.4}\n  Theta: {:.4}\n  Vega: {:.4}\n  Rho: {:.4}", 
+                delta, gamma, theta, vega, rho);
+    }
+}
\ No newline at end of file
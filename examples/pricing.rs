@@ -1,6 +1,6 @@
-use options_pricing_rusty::{OptionType, OptionPricingModel, BaseModel, BlackScholesModel, BinomialModel, MonteCarloModel};
+use options_pricing_rusty::{OptionType, OptionPricingModel, BaseModel, BlackScholesModel, BinomialModel, BinomialTree, ConvergenceAcceleration, MonteCarloModel, Dynamics, MertonJumpDiffusionModel, HestonModel, SabrModel, SabrVolatilityFormula, CevModel, VarianceGammaModel, NormalInverseGaussianModel, FiniteDifferenceModel, FiniteDifferenceScheme, EarlyExerciseMethod, ExerciseStyle, Payoff, TrinomialModel, TrinomialTree, AmericanApproximationModel, AmericanApproximation, LeastSquaresMonteCarloModel, BasisFunctions, MultiAssetMonteCarloModel, MultiAssetPayoff, BasketModel, BasketApproximation, ExchangeOptionModel, SpreadOptionModel, SpreadApproximation, QuantoModel, FxSettlement, CompoundOptionModel, ChooserOptionModel, ChooserStyle, ForwardStartModel, Cliquet, PowerOptionModel, GapOptionModel, VarianceSwapModel, RealizedVariancePayoff, Autocallable, KnockInMonitoring, DoubleBarrierModel, BarrierKnock, ParisianOptionModel, ParisianBarrier, ParisianMethod, RangeAccrual, RangeAccrualModel};
 
-fn main() {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let underlying = 120.0;
     let strike = 100.0;
@@ -8,6 +8,29 @@ fn main() {
     let volatility = 0.2; 
     let risk_free_rate = 0.05;   
     let steps = 252;
+    let jump_intensity = 0.75;
+    let jump_mean = -0.1;
+    let jump_volatility = 0.15;
+    let initial_variance = 0.04;
+    let mean_reversion = 2.0;
+    let long_run_variance = 0.04;
+    let vol_of_vol = 0.3;
+    let correlation = -0.7;
+    let alpha = 2.2;
+    let beta = 0.5;
+    let elasticity = 0.5;
+    let cev_volatility = volatility * f64::powf(underlying, 1.0 - elasticity);
+    let variance_rate = 0.2;
+    let drift = -0.14;
+    let nig_alpha = 8.0;
+    let nig_beta = -3.0;
+    let nig_scale = 0.3;
+    let price_steps = 400;
+    let stretch = f64::sqrt(1.5);
+    let dividend_yield = 0.02;
+    let foreign_rate = 0.03;
+    let fx_volatility = 0.1;
+    let fx_correlation = 0.3;
     let simulations = 10000;
 
     let option_types = [OptionType::Call, OptionType::Put];
@@ -54,6 +77,10 @@ fn main() {
         volatility,
         risk_free_rate,
         steps,
+        tree: BinomialTree::CoxRossRubinstein,
+        exercise: ExerciseStyle::European,
+        acceleration: ConvergenceAcceleration::None,
+        payoff: Payoff::Vanilla,
     };
 
     print!("\n{}\nModel: Binomial\n{}\n", header, mid);
@@ -63,20 +90,772 @@ fn main() {
         println!("Option: {:?}, Price: {:.4}", option, price);
     }
 
-    let monte_carlo_model = MonteCarloModel {
+    let monte_carlo_model = MonteCarloModel::new(underlying, strike, maturity, volatility, risk_free_rate, simulations, steps);
+
+    print!("\n{}\nModel: Monte Carlo\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+    
+    for option in &option_types {
+        let price = monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let merton_model = MertonJumpDiffusionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        jump_intensity,
+        jump_mean,
+        jump_volatility,
+    };
+
+    print!("\n{}\nModel: Merton Jump Diffusion\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = merton_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let merton_monte_carlo_model = MonteCarloModel {
+        dynamics: Dynamics::MertonJumpDiffusion { jump_intensity, jump_mean, jump_volatility },
+        ..monte_carlo_model
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Merton Jumps)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+
+    for option in &option_types {
+        let price = merton_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let heston_model = HestonModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        initial_variance,
+        mean_reversion,
+        long_run_variance,
+        vol_of_vol,
+        correlation,
+    };
+
+    print!("\n{}\nModel: Heston\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = heston_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let heston_monte_carlo_model = MonteCarloModel {
+        dynamics: Dynamics::Heston { initial_variance, mean_reversion, long_run_variance, vol_of_vol, correlation },
+        ..monte_carlo_model
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Heston QE)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+
+    for option in &option_types {
+        let price = heston_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let sabr_model = SabrModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        alpha,
+        beta,
+        correlation,
+        vol_of_vol,
+        formula: SabrVolatilityFormula::Obloj,
+    };
+
+    print!("\n{}\nModel: SABR\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = sabr_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let cev_model = CevModel {
+        underlying,
+        strike,
+        maturity,
+        volatility: cev_volatility,
+        risk_free_rate,
+        elasticity,
+    };
+
+    print!("\n{}\nModel: CEV\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = cev_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let cev_monte_carlo_model = MonteCarloModel {
+        volatility: cev_volatility,
+        dynamics: Dynamics::Cev { elasticity },
+        ..monte_carlo_model
+    };
+
+    print!("\n{}\nModel: Monte Carlo (CEV)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+
+    for option in &option_types {
+        let price = cev_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let variance_gamma_model = VarianceGammaModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        volatility,
+        variance_rate,
+        drift,
+    };
+
+    print!("\n{}\nModel: Variance Gamma\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = variance_gamma_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let variance_gamma_monte_carlo_model = MonteCarloModel {
+        dynamics: Dynamics::VarianceGamma { variance_rate, drift },
+        ..monte_carlo_model
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Variance Gamma)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+
+    for option in &option_types {
+        let price = variance_gamma_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let nig_model = NormalInverseGaussianModel {
+        underlying,
+        strike,
+        maturity,
+        risk_free_rate,
+        alpha: nig_alpha,
+        beta: nig_beta,
+        scale: nig_scale,
+    };
+
+    print!("\n{}\nModel: Normal Inverse Gaussian\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = nig_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let nig_monte_carlo_model = MonteCarloModel {
+        dynamics: Dynamics::NormalInverseGaussian { alpha: nig_alpha, beta: nig_beta, scale: nig_scale },
+        ..monte_carlo_model
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Normal Inverse Gaussian)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+
+    for option in &option_types {
+        let price = nig_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let finite_difference_model = FiniteDifferenceModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        price_steps,
+        time_steps: steps as usize,
+        scheme: FiniteDifferenceScheme::CrankNicolson { rannacher_steps: 2 },
+        exercise: ExerciseStyle::European,
+        early_exercise: EarlyExerciseMethod::Penalty,
+        payoff: Payoff::Vanilla,
+    };
+
+    print!("\n{}\nModel: Finite Difference (Crank-Nicolson)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = finite_difference_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let american_finite_difference_model = FiniteDifferenceModel {
+        exercise: ExerciseStyle::American,
+        ..finite_difference_model.clone()
+    };
+
+    print!("\n{}\nModel: Finite Difference (Crank-Nicolson, American)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = american_finite_difference_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let trinomial_model = TrinomialModel {
         underlying,
         strike,
         maturity,
         volatility,
         risk_free_rate,
-        simulations,
         steps,
+        stretch,
+        tree: TrinomialTree::KamradRitchken,
+        exercise: ExerciseStyle::European,
+        payoff: Payoff::Vanilla,
     };
 
-    print!("\n{}\nModel: Monte Carlo\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
-    
+    print!("\n{}\nModel: Trinomial (Kamrad-Ritchken)\n{}\n", header, mid);
+
     for option in &option_types {
-        let price = monte_carlo_model.price(*option);
+        let price = trinomial_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let american_trinomial_model = TrinomialModel {
+        exercise: ExerciseStyle::American,
+        ..trinomial_model.clone()
+    };
+
+    print!("\n{}\nModel: Trinomial (Kamrad-Ritchken, American)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = american_trinomial_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let trinomial_barrier = ParisianBarrier {
+        lower_barrier: 0.0,
+        upper_barrier: 1.5 * strike,
+        window: 0.1 * maturity,
+        knock: BarrierKnock::Out,
+    };
+
+    print!("\n{}\nModel: Trinomial (Kamrad-Ritchken, Parisian Up-and-Out)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = trinomial_model.barrier_price(&trinomial_barrier, *option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let leisen_reimer_model = BinomialModel {
+        steps: 51,
+        tree: BinomialTree::LeisenReimer,
+        ..binomial_model.clone()
+    };
+
+    print!("\n{}\nModel: Binomial (Leisen-Reimer, 51 Steps)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = leisen_reimer_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let bbsr_model = BinomialModel {
+        steps: 51,
+        exercise: ExerciseStyle::American,
+        acceleration: ConvergenceAcceleration::BlackScholesRichardson,
+        ..binomial_model.clone()
+    };
+
+    print!("\n{}\nModel: Binomial (BBSR, American, 51 Steps)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = bbsr_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    // No dividends, to compare with the American trees above
+    let american_approximation_model = AmericanApproximationModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield: 0.0,
+        method: AmericanApproximation::JuZhong,
+    };
+
+    print!("\n{}\nModel: American Approximation (Ju-Zhong)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = american_approximation_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let baw_model = AmericanApproximationModel {
+        method: AmericanApproximation::BaroneAdesiWhaley,
+        ..american_approximation_model.clone()
+    };
+
+    print!("\n{}\nModel: American Approximation (Barone-Adesi-Whaley)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = baw_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let bjerksund_stensland_model = AmericanApproximationModel {
+        method: AmericanApproximation::BjerksundStensland,
+        ..american_approximation_model.clone()
+    };
+
+    print!("\n{}\nModel: American Approximation (Bjerksund-Stensland)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = bjerksund_stensland_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let lsm_model = LeastSquaresMonteCarloModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        simulations,
+        exercise_dates: 50,
+        basis: BasisFunctions::Laguerre,
+        basis_degree: 3,
+        seed: 42,
+    };
+
+    print!("\n{}\nModel: Least-Squares Monte Carlo (American, Laguerre)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = lsm_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let multi_asset_model = MultiAssetMonteCarloModel {
+        underlyings: vec![underlying, underlying],
+        volatilities: vec![volatility, 0.3],
+        dividend_yields: vec![0.0, dividend_yield],
+        correlation: vec![vec![1.0, 0.5], vec![0.5, 1.0]],
+        strike,
+        maturity,
+        risk_free_rate,
+        simulations,
+        payoff: MultiAssetPayoff::Basket { weights: vec![0.5, 0.5] },
+        seed: 42,
+    };
+
+    print!("\n{}\nModel: Multi-Asset Monte Carlo (Equal-Weighted Basket of Two)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = multi_asset_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let worst_of_model = MultiAssetMonteCarloModel {
+        payoff: MultiAssetPayoff::WorstOf,
+        ..multi_asset_model.clone()
+    };
+
+    print!("\n{}\nModel: Multi-Asset Monte Carlo (Worst-Of Two)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = worst_of_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let basket_model = BasketModel {
+        underlyings: vec![underlying, underlying],
+        weights: vec![0.5, 0.5],
+        volatilities: vec![volatility, 0.3],
+        dividend_yields: vec![0.0, dividend_yield],
+        correlation: vec![vec![1.0, 0.5], vec![0.5, 1.0]],
+        strike,
+        maturity,
+        risk_free_rate,
+        method: BasketApproximation::Ju,
+    };
+
+    print!("\n{}\nModel: Basket (Ju, Equal-Weighted Basket of Two)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = basket_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let levy_basket_model = BasketModel {
+        method: BasketApproximation::Levy,
+        ..basket_model.clone()
+    };
+
+    print!("\n{}\nModel: Basket (Levy, Equal-Weighted Basket of Two)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = levy_basket_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let exchange_model = ExchangeOptionModel {
+        underlying1: underlying,
+        underlying2: strike,
+        maturity,
+        volatility1: volatility,
+        volatility2: 0.3,
+        dividend_yield1: 0.0,
+        dividend_yield2: dividend_yield,
+        correlation: 0.5,
+        risk_free_rate,
+    };
+
+    print!("\n{}\nModel: Exchange Option (Margrabe)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = exchange_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let spread_model = SpreadOptionModel {
+        underlying1: underlying,
+        underlying2: strike,
+        strike: 5.0,
+        maturity,
+        volatility1: volatility,
+        volatility2: 0.3,
+        dividend_yield1: 0.0,
+        dividend_yield2: dividend_yield,
+        correlation: 0.5,
+        risk_free_rate,
+        method: SpreadApproximation::BjerksundStensland,
+    };
+
+    print!("\n{}\nModel: Spread Option (Bjerksund-Stensland)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = spread_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let kirk_model = SpreadOptionModel {
+        method: SpreadApproximation::Kirk,
+        ..spread_model.clone()
+    };
+
+    print!("\n{}\nModel: Spread Option (Kirk)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = kirk_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let quanto_model = QuantoModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        foreign_rate,
+        dividend_yield,
+        fx_volatility,
+        fx_correlation,
+        settlement: FxSettlement::Quanto { fixed_rate: 1.0 },
+    };
+
+    print!("\n{}\nModel: Quanto\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = quanto_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let composite_model = QuantoModel {
+        strike: strike * 1.2,
+        settlement: FxSettlement::Composite { spot_rate: 1.2 },
+        ..quanto_model.clone()
+    };
+
+    print!("\n{}\nModel: Composite\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = composite_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let quanto_monte_carlo_model = MonteCarloModel {
+        dynamics: Dynamics::Quanto { foreign_rate, dividend_yield, fx_volatility, fx_correlation },
+        ..monte_carlo_model
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Quanto)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+
+    for option in &option_types {
+        let price = quanto_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let compound_model = CompoundOptionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        compound_strike: 10.0,
+        compound_maturity: 0.5 * maturity,
+        underlying_option: OptionType::Call,
+    };
+
+    print!("\n{}\nModel: Compound (On a Call)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = compound_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let chooser_model = ChooserOptionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        choice_time: 0.5 * maturity,
+        style: ChooserStyle::Simple,
+    };
+
+    print!("\n{}\nModel: Chooser (Simple)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = chooser_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let forward_start_model = ForwardStartModel {
+        underlying,
+        strike_ratio: 1.0,
+        start_time: 0.5 * maturity,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+    };
+
+    print!("\n{}\nModel: Forward Start\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = forward_start_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let cliquet = Cliquet {
+        resets: 4,
+        local_floor: 0.0,
+        local_cap: 0.08,
+        global_floor: 0.02,
+        global_cap: f64::INFINITY,
+        notional: underlying,
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Cliquet)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+    println!("Resets: {}, Price: {:.4}", cliquet.resets, monte_carlo_model.cliquet_price(&cliquet));
+
+    // No dividends, to compare with the Monte Carlo, binomial and finite difference payoffs below
+    let power_model = PowerOptionModel {
+        underlying,
+        strike: strike.powi(2),
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield: 0.0,
+        exponent: 2.0,
+    };
+
+    print!("\n{}\nModel: Power (Squared)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = power_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let gap_model = GapOptionModel {
+        underlying,
+        strike,
+        trigger_strike: 1.05 * strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield: 0.0,
+    };
+
+    print!("\n{}\nModel: Gap\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = gap_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let power_monte_carlo_model = MonteCarloModel {
+        strike: strike.powi(2),
+        payoff: Payoff::Power { exponent: 2.0 },
+        ..monte_carlo_model
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Power, Squared)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+
+    for option in &option_types {
+        let price = power_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let gap_binomial_model = BinomialModel {
+        payoff: Payoff::Gap { trigger_strike: 1.05 * strike },
+        ..binomial_model.clone()
+    };
+
+    print!("\n{}\nModel: Binomial (Gap)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = gap_binomial_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let gap_finite_difference_model = FiniteDifferenceModel {
+        payoff: Payoff::Gap { trigger_strike: 1.05 * strike },
+        ..finite_difference_model.clone()
+    };
+
+    print!("\n{}\nModel: Finite Difference (Gap)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = gap_finite_difference_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    // Strip of strikes from 20% to 300% of spot with a linear skew in implied volatility
+    let swap_strikes: Vec<f64> = (20..=300).map(|percent| percent as f64 * underlying / 100.0).collect();
+    let variance_swap_model = VarianceSwapModel {
+        underlying,
+        maturity,
+        risk_free_rate,
+        dividend_yield,
+        volatilities: swap_strikes.iter().map(|k| f64::max(volatility - 0.1 * (k - underlying) / underlying, 0.05)).collect(),
+        strikes: swap_strikes,
+    };
+    let variance_of_variance = heston_monte_carlo_model.realized_variance_moments()?.variance;
+
+    print!("\n{}\nModel: Variance Swap (Static Replication)\n{}\n", header, mid);
+    println!("Fair Variance Strike: {:.6}", variance_swap_model.fair_variance_strike());
+    println!("Fair Volatility Strike (Heston Convexity): {:.4}", variance_swap_model.fair_volatility_strike(variance_of_variance));
+
+    let variance_swap = RealizedVariancePayoff::VarianceSwap { strike: volatility.powi(2), notional: 1.0 };
+    let volatility_swap = RealizedVariancePayoff::VolatilitySwap { strike: volatility, notional: 1.0 };
+
+    print!("\n{}\nModel: Monte Carlo (Heston, Realised Variance)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+    println!("Variance Swap: {:.6}", heston_monte_carlo_model.realized_variance_price(&variance_swap));
+    println!("Volatility Swap: {:.6}", heston_monte_carlo_model.realized_variance_price(&volatility_swap));
+
+    // Quarterly autocallable with memory coupons and a continuous 60% knock-in put
+    let autocallable = Autocallable {
+        observation_times: (1..=4).map(|quarter| quarter as f64 * maturity / 4.0).collect(),
+        autocall_barrier: 1.0,
+        coupon_barrier: 0.7,
+        coupon: 0.02,
+        memory: true,
+        knock_in_barrier: 0.6,
+        knock_in_monitoring: KnockInMonitoring::Continuous,
+        put_strike: 1.0,
+        notional: 100.0,
+    };
+
+    print!("\n{}\nModel: Monte Carlo (Autocallable)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+    let report = monte_carlo_model.autocallable_report(&autocallable)?;
+    println!("Price: {:.4}, Expected Life: {:.4}, Knock-In Probability: {:.4}", report.price, report.expected_life, report.knock_in_probability);
+    println!("Autocall Probabilities: {:.4?}", report.autocall_probabilities);
+
+    print!("\n{}\nModel: Multi-Asset Monte Carlo (Worst-Of Autocallable)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+    let report = multi_asset_model.autocallable_report(&autocallable, steps)?;
+    println!("Price: {:.4}, Expected Life: {:.4}, Knock-In Probability: {:.4}", report.price, report.expected_life, report.knock_in_probability);
+    println!("Autocall Probabilities: {:.4?}", report.autocall_probabilities);
+
+    let double_barrier_model = DoubleBarrierModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        lower_barrier: 0.8 * strike,
+        upper_barrier: 1.6 * strike,
+        knock: BarrierKnock::Out,
+    };
+
+    print!("\n{}\nModel: Double Barrier (Ikeda-Kunitomo, Knock-Out)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = double_barrier_model.price(*option);
         println!("Option: {:?}, Price: {:.4}", option, price);
     }
-}
+
+    let parisian_model = ParisianOptionModel {
+        underlying,
+        strike,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield,
+        barrier: ParisianBarrier {
+            lower_barrier: 0.0,
+            upper_barrier: 1.5 * strike,
+            window: 0.1 * maturity,
+            knock: BarrierKnock::Out,
+        },
+        method: ParisianMethod::FiniteDifference { price_steps: 400, time_steps: 500 },
+    };
+
+    print!("\n{}\nModel: Parisian Up-and-Out (Finite Difference)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = parisian_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    let parisian_monte_carlo_model = ParisianOptionModel {
+        method: ParisianMethod::MonteCarlo { simulations, steps, seed: 42 },
+        ..parisian_model.clone()
+    };
+
+    print!("\n{}\nModel: Parisian Up-and-Out (Monte Carlo)\n{}\n", header, mid);
+
+    for option in &option_types {
+        let price = parisian_monte_carlo_model.price(*option);
+        println!("Option: {:?}, Price: {:.4}", option, price);
+    }
+
+    // Daily range accrual paying 0.02% a day while the underlying stays between 110 and 140
+    let range_accrual = RangeAccrual {
+        observation_times: (1..=steps).map(|day| day as f64 * maturity / steps as f64).collect(),
+        lower_barrier: 110.0,
+        upper_barrier: 140.0,
+        coupon: 0.0002,
+        notional: 100.0,
+    };
+
+    // No dividends, to compare with the Monte Carlo price
+    let range_accrual_model = RangeAccrualModel {
+        underlying,
+        maturity,
+        volatility,
+        risk_free_rate,
+        dividend_yield: 0.0,
+        spread_width: 0.01,
+    };
+
+    print!("\n{}\nModel: Range Accrual (Digital Call Spread Replication)\n{}\n", header, mid);
+    println!("Price: {:.4}", range_accrual_model.range_accrual_price(&range_accrual));
+
+    print!("\n{}\nModel: Monte Carlo (Range Accrual)\nSteps: {}\nSimlulations: {}\n{}\n", header, steps, simulations, mid);
+    println!("Price: {:.4}", monte_carlo_model.range_accrual_price(&range_accrual)?);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,256 @@
+// Special functions and distributions not provided by `distrs`
+use distrs::Normal;
+
+// ln Γ(x) via the Lanczos approximation (g = 7, n = 9)
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection formula: Γ(x)Γ(1 - x) = π / sin(πx)
+        return f64::ln(std::f64::consts::PI / f64::sin(std::f64::consts::PI * x).abs()) - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFICIENTS[0], |acc, (i, c)| acc + c / (x + i as f64));
+
+    0.5 * f64::ln(2.0 * std::f64::consts::PI) + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+// Regularised lower incomplete gamma function P(a, x) = γ(a, x) / Γ(a)
+pub(crate) fn regularized_gamma_p(a: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 1000;
+    const EPSILON: f64 = 1e-15;
+
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    let log_prefactor = a * x.ln() - x - ln_gamma(a);
+
+    if x < a + 1.0 {
+        // Series expansion: P(a, x) = e^(-x) x^a / Γ(a) Σ x^n / (a (a+1) ... (a+n))
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (sum.ln() + log_prefactor).exp()
+    } else {
+        // Continued fraction for Q(a, x) = 1 - P(a, x), evaluated with the modified Lentz method
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for n in 1..MAX_ITERATIONS {
+            let an = -(n as f64) * (n as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        1.0 - (log_prefactor + h.ln()).exp()
+    }
+}
+
+// CDF of the noncentral chi-squared distribution with `degrees_of_freedom` and `noncentrality`,
+// summed as a Poisson(λ/2) mixture of central chi-squared CDFs starting from the largest weight
+pub(crate) fn noncentral_chi_squared_cdf(x: f64, degrees_of_freedom: f64, noncentrality: f64) -> f64 {
+    const MAX_TERMS: usize = 10_000;
+    const EPSILON: f64 = 1e-14;
+    // Beyond this the Poisson mixture needs too many terms and Sankaran's approximation is accurate
+    const LARGE_PARAMETER: f64 = 1e4;
+
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    if noncentrality > LARGE_PARAMETER || degrees_of_freedom > LARGE_PARAMETER {
+        return sankaran_approximation(x, degrees_of_freedom, noncentrality);
+    }
+
+    let half_lambda = 0.5 * noncentrality;
+    if half_lambda <= 0.0 {
+        return regularized_gamma_p(0.5 * degrees_of_freedom, 0.5 * x);
+    }
+
+    let poisson_weight = |j: f64| (-half_lambda + j * half_lambda.ln() - ln_gamma(j + 1.0)).exp();
+    let term = |j: f64| poisson_weight(j) * regularized_gamma_p(0.5 * degrees_of_freedom + j, 0.5 * x);
+
+    let mode = half_lambda.floor();
+    let mut total = term(mode);
+
+    // Sum upwards from the mode until the Poisson tail is negligible
+    let mut remaining_weight = 1.0 - poisson_weight(mode);
+    for j in 1..MAX_TERMS {
+        let weight = poisson_weight(mode + j as f64);
+        total += weight * regularized_gamma_p(0.5 * degrees_of_freedom + mode + j as f64, 0.5 * x);
+        remaining_weight -= weight;
+        if weight < EPSILON || remaining_weight < EPSILON {
+            break;
+        }
+    }
+
+    // Then downwards to zero
+    let mut j = mode - 1.0;
+    while j >= 0.0 {
+        let value = term(j);
+        total += value;
+        if poisson_weight(j) < EPSILON {
+            break;
+        }
+        j -= 1.0;
+    }
+
+    total.clamp(0.0, 1.0)
+}
+
+// Sankaran (1963) normal approximation to the noncentral chi-squared CDF
+fn sankaran_approximation(x: f64, degrees_of_freedom: f64, noncentrality: f64) -> f64 {
+    let (k, lambda) = (degrees_of_freedom, noncentrality);
+
+    let h = 1.0 - 2.0 / 3.0 * (k + lambda) * (k + 3.0 * lambda) / (k + 2.0 * lambda).powi(2);
+    let p = (k + 2.0 * lambda) / (k + lambda).powi(2);
+    let m = (h - 1.0) * (1.0 - 3.0 * h);
+
+    let numerator = (x / (k + lambda)).powf(h) - (1.0 + h * p * (h - 1.0 - 0.5 * (2.0 - h) * m * p));
+    let denominator = h * f64::sqrt(2.0 * p) * (1.0 + 0.5 * m * p);
+
+    Normal::cdf(numerator / denominator, 0.0, 1.0)
+}
+
+// Standard normal density
+pub(crate) fn normal_pdf(x: f64) -> f64 {
+    Normal::pdf(x, 0.0, 1.0)
+}
+
+// Standard normal CDF
+pub(crate) fn normal_cdf(x: f64) -> f64 {
+    Normal::cdf(x, 0.0, 1.0)
+}
+
+// Bivariate standard normal CDF P(X < x, Y < y) with correlation ρ, using Genz's (2004)
+// refinement of the Drezner-Wesolowsky Gauss-Legendre method (accurate to about 1e-15)
+pub(crate) fn bivariate_normal_cdf(x: f64, y: f64, rho: f64) -> f64 {
+    const X6: [f64; 3] = [-0.932_469_514_203_152_2, -0.661_209_386_466_264_7, -0.238_619_186_083_197];
+    const W6: [f64; 3] = [0.171_324_492_379_170_5, 0.360_761_573_048_138_4, 0.467_913_934_572_690_4];
+    const X12: [f64; 6] = [
+        -0.981_560_634_246_719_1, -0.904_117_256_370_475, -0.769_902_674_194_305,
+        -0.587_317_954_286_617_1, -0.367_831_498_998_180_2, -0.125_233_408_511_469_2,
+    ];
+    const W12: [f64; 6] = [
+        0.047_175_336_386_511_77, 0.106_939_325_995_318_3, 0.160_078_328_543_346_4,
+        0.203_167_426_723_065_9, 0.233_492_536_538_354_7, 0.249_147_045_813_402_9,
+    ];
+    const X20: [f64; 10] = [
+        -0.993_128_599_185_094_9, -0.963_971_927_277_913_8, -0.912_234_428_251_326,
+        -0.839_116_971_822_218_8, -0.746_331_906_460_150_8, -0.636_053_680_726_515,
+        -0.510_867_001_950_827_1, -0.373_706_088_715_419_6, -0.227_785_851_141_645_1,
+        -0.076_526_521_133_497_33,
+    ];
+    const W20: [f64; 10] = [
+        0.017_614_007_139_152_12, 0.040_601_429_800_386_94, 0.062_672_048_334_109_06,
+        0.083_276_741_576_704_75, 0.101_930_119_817_240_4, 0.118_194_531_961_518_4,
+        0.131_688_638_449_176_6, 0.142_096_109_318_382_1, 0.149_172_986_472_603_7,
+        0.152_753_387_130_725_9,
+    ];
+
+    let (nodes, weights): (&[f64], &[f64]) = if rho.abs() < 0.3 {
+        (&X6, &W6)
+    } else if rho.abs() < 0.75 {
+        (&X12, &W12)
+    } else {
+        (&X20, &W20)
+    };
+
+    // Genz computes P(X > h, Y > k)
+    let h = -x;
+    let mut k = -y;
+    let mut hk = h * k;
+    let two_pi = 2.0 * std::f64::consts::PI;
+
+    if rho.abs() < 0.925 {
+        let hs = 0.5 * (h * h + k * k);
+        let asr = rho.asin();
+        let mut total = 0.0;
+        for (node, weight) in nodes.iter().zip(weights) {
+            for sign in [-1.0, 1.0] {
+                let sn = f64::sin(0.5 * asr * (sign * node + 1.0));
+                total += weight * f64::exp((sn * hk - hs) / (1.0 - sn * sn));
+            }
+        }
+        return total * asr / (2.0 * two_pi) + normal_cdf(-h) * normal_cdf(-k);
+    }
+
+    if rho < 0.0 {
+        k = -k;
+        hk = -hk;
+    }
+
+    let mut total = 0.0;
+    if rho.abs() < 1.0 {
+        let a_squared = (1.0 - rho) * (1.0 + rho);
+        let mut a = a_squared.sqrt();
+        let b_squared = (h - k).powi(2);
+        let c = (4.0 - hk) / 8.0;
+        let d = (12.0 - hk) / 16.0;
+
+        total = a * f64::exp(-0.5 * (b_squared / a_squared + hk))
+            * (1.0 - c * (b_squared - a_squared) * (1.0 - d * b_squared / 5.0) / 3.0 + c * d * a_squared.powi(2) / 5.0);
+        if hk > -160.0 {
+            let b = b_squared.sqrt();
+            total -= f64::exp(-0.5 * hk) * two_pi.sqrt() * normal_cdf(-b / a) * b
+                * (1.0 - c * b_squared * (1.0 - d * b_squared / 5.0) / 3.0);
+        }
+
+        a *= 0.5;
+        for (node, weight) in nodes.iter().zip(weights) {
+            for sign in [-1.0, 1.0] {
+                let xs = (a * (sign * node + 1.0)).powi(2);
+                let rs = (1.0 - xs).sqrt();
+                let asr = -0.5 * (b_squared / xs + hk);
+                if asr > -100.0 {
+                    total += a * weight * asr.exp()
+                        * (f64::exp(-hk * xs / (2.0 * (1.0 + rs).powi(2))) / rs - (1.0 + c * xs * (1.0 + d * xs)));
+                }
+            }
+        }
+        total = -total / two_pi;
+    }
+
+    if rho > 0.0 {
+        total + normal_cdf(-f64::max(h, k))
+    } else {
+        -total + f64::max(0.0, normal_cdf(-h) - normal_cdf(-k))
+    }
+}
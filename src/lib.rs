@@ -4,6 +4,24 @@ pub enum OptionType {
     Put,
 }
 
+#[derive(PartialEq,Debug,Clone,Copy)]
+pub enum ExerciseStyle {
+    European,
+    American,
+}
+
+// Terminal payoff of a single-asset option with strike K
+#[derive(PartialEq,Debug,Clone,Copy)]
+pub enum Payoff {
+    // max(S - K, 0) for a call and max(K - S, 0) for a put
+    Vanilla,
+    // Asymmetric power option: max(S^n - K, 0) for a call and max(K - S^n, 0) for a put
+    Power { exponent: f64 },
+    // Gap option: S - K if S > trigger for a call and K - S if S < trigger for a put, which
+    // can be negative
+    Gap { trigger_strike: f64 },
+}
+
 pub trait OptionPricingModel {
     fn price(&self, option_type: OptionType) -> f64;
     fn delta(&self, option_type: OptionType) -> f64;
@@ -13,14 +31,75 @@ pub trait OptionPricingModel {
     fn rho(&self, option_type: OptionType) -> f64;
 }
 
+// Models with a closed-form characteristic function of the log terminal price,
+// which can be priced by Fourier inversion
+pub trait CharacteristicFunction {
+    // E[exp(iu ln S_T)] under the risk-neutral measure
+    fn characteristic_function(&self, u: num_complex::Complex64) -> num_complex::Complex64;
+}
+
+mod distributions;
+mod linear_algebra;
+
 pub mod pricing_models {
     pub mod base;
     pub mod black_scholes;
     pub mod binomial;
+    pub mod trinomial;
+    pub(crate) mod lattice;
     pub mod monte_carlo;
+    pub mod least_squares_monte_carlo;
+    pub mod multi_asset_monte_carlo;
+    pub mod autocallable;
+    pub mod range_accrual;
+    pub mod barrier;
+    pub mod double_barrier;
+    pub mod basket;
+    pub mod spread;
+    pub mod quanto;
+    pub mod compound;
+    pub mod chooser;
+    pub mod forward_start;
+    pub mod power;
+    pub mod gap;
+    pub mod variance_swap;
+    pub mod merton;
+    pub mod heston;
+    pub mod sabr;
+    pub mod cev;
+    pub mod fourier;
+    pub mod variance_gamma;
+    pub mod normal_inverse_gaussian;
+    pub mod finite_difference;
+    pub mod american_approximation;
 }
 
 pub use pricing_models::base::BaseModel;
 pub use pricing_models::black_scholes::BlackScholesModel;
-pub use pricing_models::binomial::BinomialModel;
-pub use pricing_models::monte_carlo::MonteCarloModel;
\ No newline at end of file
+pub use pricing_models::basket::{BasketModel, BasketApproximation};
+pub use pricing_models::spread::{ExchangeOptionModel, SpreadOptionModel, SpreadApproximation};
+pub use pricing_models::quanto::{QuantoModel, FxSettlement};
+pub use pricing_models::compound::CompoundOptionModel;
+pub use pricing_models::chooser::{ChooserOptionModel, ChooserStyle};
+pub use pricing_models::forward_start::ForwardStartModel;
+pub use pricing_models::power::PowerOptionModel;
+pub use pricing_models::gap::GapOptionModel;
+pub use pricing_models::variance_swap::VarianceSwapModel;
+pub use pricing_models::binomial::{BinomialModel, BinomialTree, ConvergenceAcceleration};
+pub use pricing_models::trinomial::{TrinomialModel, TrinomialTree};
+pub use pricing_models::monte_carlo::{MonteCarloModel, Dynamics, Cliquet, RealizedVariancePayoff, RealizedVarianceMoments, InsufficientPathsError};
+pub use pricing_models::least_squares_monte_carlo::{LeastSquaresMonteCarloModel, BasisFunctions};
+pub use pricing_models::multi_asset_monte_carlo::{MultiAssetMonteCarloModel, MultiAssetPayoff, DimensionError, CorrelationError, MultiAssetError};
+pub use pricing_models::autocallable::{Autocallable, AutocallableReport, KnockInMonitoring, ScheduleError};
+pub use pricing_models::range_accrual::{RangeAccrual, RangeAccrualModel};
+pub use pricing_models::barrier::{ParisianOptionModel, ParisianBarrier, ParisianMethod, BarrierKnock};
+pub use pricing_models::double_barrier::DoubleBarrierModel;
+pub use pricing_models::merton::MertonJumpDiffusionModel;
+pub use pricing_models::heston::HestonModel;
+pub use pricing_models::sabr::{SabrModel, SabrVolatilityFormula};
+pub use pricing_models::cev::CevModel;
+pub use pricing_models::variance_gamma::VarianceGammaModel;
+pub use pricing_models::normal_inverse_gaussian::NormalInverseGaussianModel;
+pub use pricing_models::fourier::{FourierEngine, FourierMethod, FourierError};
+pub use pricing_models::finite_difference::{FiniteDifferenceModel, FiniteDifferenceScheme, EarlyExerciseMethod};
+pub use pricing_models::american_approximation::{AmericanApproximationModel, AmericanApproximation};
\ No newline at end of file
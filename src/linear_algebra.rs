@@ -0,0 +1,146 @@
+// Small dense linear algebra for correlation matrices
+
+// Lower-triangular L with LLᵀ = A, or None if A is not positive definite
+pub(crate) fn cholesky(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut lower = vec![vec![0.0; n]; n];
+
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+            if i == j {
+                let pivot = matrix[i][i] - sum;
+                if pivot <= 0.0 {
+                    return None;
+                }
+                lower[i][i] = pivot.sqrt();
+            } else {
+                lower[i][j] = (matrix[i][j] - sum) / lower[j][j];
+            }
+        }
+    }
+
+    Some(lower)
+}
+
+// Eigenvalues and eigenvectors (as columns) of a symmetric matrix by cyclic Jacobi rotations
+pub(crate) fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    const MAX_SWEEPS: usize = 100;
+    const TOLERANCE: f64 = 1e-14;
+
+    let n = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut vectors = vec![vec![0.0; n]; n];
+    for (i, row) in vectors.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..n).flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j].powi(2))
+            .sum();
+        if off_diagonal < TOLERANCE {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() < f64::MIN_POSITIVE {
+                    continue;
+                }
+
+                // Rotation angle that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + f64::sqrt(theta * theta + 1.0));
+                let c = 1.0 / f64::sqrt(t * t + 1.0);
+                let s = t * c;
+
+                // A' = JᵀAJ: rotate columns p and q, then rows p and q
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (row_p, row_q) = (a[p].clone(), a[q].clone());
+                a[p] = row_p.iter().zip(&row_q).map(|(apk, aqk)| c * apk - s * aqk).collect();
+                a[q] = row_p.iter().zip(&row_q).map(|(apk, aqk)| s * apk + c * aqk).collect();
+                for row in vectors.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[i][i]).collect(), vectors)
+}
+
+// Repair a correlation matrix that is not positive definite by clipping its eigenvalues at a
+// small positive floor and rescaling back to a unit diagonal
+pub(crate) fn nearest_correlation(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    const EIGENVALUE_FLOOR: f64 = 1e-8;
+
+    let n = matrix.len();
+    let (values, vectors) = symmetric_eigen(matrix);
+    let values: Vec<f64> = values.iter().map(|&v| f64::max(v, EIGENVALUE_FLOOR)).collect();
+
+    // V diag(λ) Vᵀ
+    let mut repaired = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            repaired[i][j] = (0..n).map(|k| vectors[i][k] * values[k] * vectors[j][k]).sum();
+        }
+    }
+
+    let scale: Vec<f64> = (0..n).map(|i| repaired[i][i].sqrt()).collect();
+    for i in 0..n {
+        for j in 0..n {
+            repaired[i][j] /= scale[i] * scale[j];
+        }
+    }
+
+    repaired
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product_with_transpose(factor: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        let n = factor.len();
+        (0..n).map(|i| (0..n).map(|j| (0..n).map(|k| factor[i][k] * factor[j][k]).sum()).collect()).collect()
+    }
+
+    #[test]
+    fn cholesky_factor_reproduces_the_matrix() {
+        let matrix = vec![vec![1.0, 0.5, 0.2], vec![0.5, 1.0, -0.3], vec![0.2, -0.3, 1.0]];
+        let lower = cholesky(&matrix).expect("matrix is positive definite");
+
+        for (i, row) in lower.iter().enumerate() {
+            assert!(row[i + 1..].iter().all(|&entry| entry == 0.0));
+        }
+        for (row, expected) in product_with_transpose(&lower).iter().zip(&matrix) {
+            for (entry, expected) in row.iter().zip(expected) {
+                assert!((entry - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn repaired_correlation_is_positive_definite_with_unit_diagonal() {
+        // Pairwise consistent but jointly impossible: eigenvalue 1 - 0.9 - 0.9 < 0
+        let matrix = vec![vec![1.0, 0.9, -0.9], vec![0.9, 1.0, 0.9], vec![-0.9, 0.9, 1.0]];
+        assert!(cholesky(&matrix).is_none());
+
+        let repaired = nearest_correlation(&matrix);
+        assert!(cholesky(&repaired).is_some());
+        for i in 0..3 {
+            assert!((repaired[i][i] - 1.0).abs() < 1e-12);
+            for j in 0..3 {
+                assert!((repaired[i][j] - repaired[j][i]).abs() < 1e-12);
+                assert!(repaired[i][j].signum() == matrix[i][j].signum());
+            }
+        }
+    }
+}
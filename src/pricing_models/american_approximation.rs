@@ -0,0 +1,464 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+use crate::distributions::{normal_cdf, normal_pdf, bivariate_normal_cdf};
+
+// Closed-form approximation used for the early exercise premium
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum AmericanApproximation {
+    // Barone-Adesi and Whaley (1987) quadratic approximation
+    BaroneAdesiWhaley,
+    // Bjerksund and Stensland (2002) two-step flat exercise boundary
+    BjerksundStensland,
+    // Ju and Zhong (1999) refinement of the quadratic approximation
+    JuZhong,
+}
+
+#[derive(Clone)]
+pub struct AmericanApproximationModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+    pub method: AmericanApproximation,
+}
+
+impl AmericanApproximationModel {
+    const TOLERANCE: f64 = 1e-8;
+    const MAX_ITERATIONS: usize = 500;
+
+    // Cost of carry: b = r - q
+    fn carry(&self) -> f64 {
+        self.risk_free_rate - self.dividend_yield
+    }
+
+    // European price with continuous dividend yield, as Black-Scholes on the dividend-adjusted spot S e^(-qT)
+    fn european_price(&self, asset_price: f64, option_type: OptionType) -> f64 {
+        BlackScholesModel {
+            underlying: asset_price * f64::exp(-self.dividend_yield * self.maturity),
+            strike: self.strike,
+            maturity: self.maturity,
+            volatility: self.volatility,
+            risk_free_rate: self.risk_free_rate,
+        }
+        .price(option_type)
+    }
+
+    fn d1(&self, asset_price: f64) -> f64 {
+        (f64::ln(asset_price / self.strike) + (self.carry() + 0.5 * self.volatility.powi(2)) * self.maturity)
+            / (self.volatility * f64::sqrt(self.maturity))
+    }
+
+    // ±1 for calls and puts
+    fn sign(option_type: OptionType) -> f64 {
+        match option_type {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        }
+    }
+
+    // α/h with α = 2r/σ² and h = 1 - e^(-rT), which tends to 2/(σ²T) as r → 0
+    fn alpha_over_h(&self) -> f64 {
+        let (r, t, sigma2) = (self.risk_free_rate, self.maturity, self.volatility.powi(2));
+        if (r * t).abs() < 1e-10 {
+            2.0 / (sigma2 * t)
+        } else {
+            2.0 * r / (sigma2 * (1.0 - f64::exp(-r * t)))
+        }
+    }
+
+    // λ(h) = (-(β - 1) + φ√((β - 1)² + 4α/h)) / 2, with α = 2r/σ², β = 2b/σ², h = 1 - e^(-rT)
+    fn quadratic_exponent(&self, option_type: OptionType) -> f64 {
+        let beta = 2.0 * self.carry() / self.volatility.powi(2);
+        0.5 * (-(beta - 1.0) + Self::sign(option_type) * f64::sqrt((beta - 1.0).powi(2) + 4.0 * self.alpha_over_h()))
+    }
+
+    // Critical price S* where φ(S* - K) = V_E(S*) + φ(1 - e^((b-r)T) N(φ d1(S*))) S*/λ, solved by
+    // Newton's method from the seed values in Barone-Adesi and Whaley
+    fn critical_price(&self, option_type: OptionType) -> f64 {
+        let (k, t, r, b, sigma) = (self.strike, self.maturity, self.risk_free_rate, self.carry(), self.volatility);
+        let phi = Self::sign(option_type);
+        let lambda = self.quadratic_exponent(option_type);
+        let carry_discount = f64::exp((b - r) * t);
+
+        // Seed from the perpetual boundary S_∞ = K / (1 - 1/λ_∞)
+        let n = 2.0 * b / sigma.powi(2);
+        let m = 2.0 * r / sigma.powi(2);
+        let lambda_infinity = 0.5 * (-(n - 1.0) + phi * f64::sqrt((n - 1.0).powi(2) + 4.0 * m));
+        let s_infinity = k / (1.0 - 1.0 / lambda_infinity);
+        let h = -phi * (b * t + phi * 2.0 * sigma * f64::sqrt(t)) * k / (phi * (s_infinity - k));
+        let mut s = k + (s_infinity - k) * (1.0 - f64::exp(h));
+
+        for _ in 0..Self::MAX_ITERATIONS {
+            let d1 = self.d1(s);
+            let probability = normal_cdf(phi * d1);
+            let lhs = phi * (s - k);
+            let rhs = self.european_price(s, option_type) + phi * (1.0 - carry_discount * probability) * s / lambda;
+            if (lhs - rhs).abs() / k < Self::TOLERANCE {
+                break;
+            }
+
+            // Slope of the right-hand side in S
+            let slope = phi * carry_discount * probability * (1.0 - 1.0 / lambda)
+                + phi * (1.0 - phi * carry_discount * normal_pdf(d1) / (sigma * f64::sqrt(t))) / lambda;
+            s = (phi * k + rhs - slope * s) / (phi - slope);
+        }
+
+        s
+    }
+
+    fn barone_adesi_whaley(&self, option_type: OptionType) -> f64 {
+        let phi = Self::sign(option_type);
+        let lambda = self.quadratic_exponent(option_type);
+        let critical = self.critical_price(option_type);
+        let s = self.underlying;
+
+        if phi * (s - critical) >= 0.0 {
+            return phi * (s - self.strike);
+        }
+
+        let carry_discount = f64::exp((self.carry() - self.risk_free_rate) * self.maturity);
+        let a = phi * critical / lambda * (1.0 - carry_discount * normal_cdf(phi * self.d1(critical)));
+        self.european_price(s, option_type) + a * (s / critical).powf(lambda)
+    }
+
+    fn ju_zhong(&self, option_type: OptionType) -> f64 {
+        let (k, r, sigma) = (self.strike, self.risk_free_rate, self.volatility);
+        let phi = Self::sign(option_type);
+        let lambda = self.quadratic_exponent(option_type);
+        let critical = self.critical_price(option_type);
+        let s = self.underlying;
+
+        if phi * (s - critical) >= 0.0 {
+            return phi * (s - k);
+        }
+
+        let alpha_over_h = self.alpha_over_h();
+        let beta = 2.0 * self.carry() / sigma.powi(2);
+        let h = 1.0 - f64::exp(-r * self.maturity);
+
+        // Early exercise premium at the boundary: h A(h) = φ(S* - K) - V_E(S*)
+        let premium = phi * (critical - k) - self.european_price(critical, option_type);
+
+        // α ∂V_E(S*)/∂h = 2 ∂V_E/∂T / (σ²(1 - h)), since ∂h/∂T = r(1 - h), with ∂V_E/∂T by central
+        // difference. Terms are kept multiplied by α so they stay finite as r → 0.
+        let epsilon = 1e-4 * self.maturity;
+        let mut model_up = self.clone();
+        model_up.maturity += epsilon;
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let d_european_d_t = (model_up.european_price(critical, option_type)
+            - model_down.european_price(critical, option_type)) / (2.0 * epsilon);
+        let alpha_d_european_d_h = 2.0 * d_european_d_t / (sigma.powi(2) * (1.0 - h));
+
+        // α λ'(h) = -φ(α/h)² / √((β - 1)² + 4α/h)
+        let alpha_d_lambda_d_h = -phi * alpha_over_h.powi(2) / f64::sqrt((beta - 1.0).powi(2) + 4.0 * alpha_over_h);
+        let denominator = 2.0 * lambda + beta - 1.0;
+
+        let b_coefficient = (1.0 - h) * alpha_d_lambda_d_h / (2.0 * denominator);
+        let c_coefficient = -(1.0 - h) / denominator
+            * (alpha_d_european_d_h / premium + alpha_over_h + alpha_d_lambda_d_h / denominator);
+
+        let log_ratio = f64::ln(s / critical);
+        let chi = b_coefficient * log_ratio.powi(2) + c_coefficient * log_ratio;
+
+        self.european_price(s, option_type) + premium * (s / critical).powf(lambda) / (1.0 - chi)
+    }
+
+    // Bjerksund-Stensland (2002) call; puts use the put-call transformation
+    // P(S, K, T, r, b, σ) = C(K, S, T, r - b, -b, σ)
+    fn bjerksund_stensland(&self, option_type: OptionType) -> f64 {
+        let (s, k, r, b) = match option_type {
+            OptionType::Call => (self.underlying, self.strike, self.risk_free_rate, self.carry()),
+            OptionType::Put => (self.strike, self.underlying, self.risk_free_rate - self.carry(), -self.carry()),
+        };
+        bjerksund_stensland_call(s, k, self.maturity, r, b, self.volatility)
+    }
+}
+
+fn bjerksund_stensland_call(s: f64, k: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let sigma2 = sigma.powi(2);
+
+    // Never optimal to exercise early without a positive dividend yield
+    if b >= r {
+        let european = BlackScholesModel {
+            underlying: s * f64::exp((b - r) * t),
+            strike: k,
+            maturity: t,
+            volatility: sigma,
+            risk_free_rate: r,
+        };
+        return european.price(OptionType::Call);
+    }
+
+    let beta = (0.5 - b / sigma2) + f64::sqrt((b / sigma2 - 0.5).powi(2) + 2.0 * r / sigma2);
+    let b_infinity = beta / (beta - 1.0) * k;
+    let b_zero = f64::max(k, r / (r - b) * k);
+
+    // The boundary is flat on [0, t1] and [t1, T]
+    let t1 = 0.5 * (f64::sqrt(5.0) - 1.0) * t;
+    let h1 = -(b * t1 + 2.0 * sigma * f64::sqrt(t1)) * k * k / ((b_infinity - b_zero) * b_zero);
+    let h2 = -(b * t + 2.0 * sigma * f64::sqrt(t)) * k * k / ((b_infinity - b_zero) * b_zero);
+    let i1 = b_zero + (b_infinity - b_zero) * (1.0 - f64::exp(h1));
+    let i2 = b_zero + (b_infinity - b_zero) * (1.0 - f64::exp(h2));
+    let alpha1 = (i1 - k) * i1.powf(-beta);
+    let alpha2 = (i2 - k) * i2.powf(-beta);
+
+    if s >= i2 {
+        return s - k;
+    }
+
+    let phi = |gamma: f64, h: f64, i: f64| bs_phi(s, t1, gamma, h, i, r, b, sigma);
+    let psi = |gamma: f64, h: f64| bs_psi(s, t, gamma, h, i2, i1, t1, r, b, sigma);
+
+    alpha2 * s.powf(beta) - alpha2 * phi(beta, i2, i2)
+        + phi(1.0, i2, i2) - phi(1.0, i1, i2)
+        - k * phi(0.0, i2, i2) + k * phi(0.0, i1, i2)
+        + alpha1 * phi(beta, i1, i2) - alpha1 * psi(beta, i1)
+        + psi(1.0, i1) - psi(1.0, k)
+        - k * psi(0.0, i1) + k * psi(0.0, k)
+}
+
+// φ(S, T, γ, H, I) from Bjerksund-Stensland
+#[allow(clippy::too_many_arguments)]
+fn bs_phi(s: f64, t: f64, gamma: f64, h: f64, i: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let sigma_sqrt_t = sigma * f64::sqrt(t);
+    let lambda = (-r + gamma * b + 0.5 * gamma * (gamma - 1.0) * sigma.powi(2)) * t;
+    let d = -(f64::ln(s / h) + (b + (gamma - 0.5) * sigma.powi(2)) * t) / sigma_sqrt_t;
+    let kappa = 2.0 * b / sigma.powi(2) + 2.0 * gamma - 1.0;
+
+    f64::exp(lambda) * s.powf(gamma)
+        * (normal_cdf(d) - (i / s).powf(kappa) * normal_cdf(d - 2.0 * f64::ln(i / s) / sigma_sqrt_t))
+}
+
+// ψ(S, T, γ, H, I2, I1, t1) from Bjerksund-Stensland, using the bivariate normal CDF
+#[allow(clippy::too_many_arguments)]
+fn bs_psi(s: f64, t: f64, gamma: f64, h: f64, i2: f64, i1: f64, t1: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let drift = b + (gamma - 0.5) * sigma.powi(2);
+    let (sqrt_t1, sqrt_t) = (sigma * f64::sqrt(t1), sigma * f64::sqrt(t));
+
+    let e1 = (f64::ln(s / i1) + drift * t1) / sqrt_t1;
+    let e2 = (f64::ln(i2 * i2 / (s * i1)) + drift * t1) / sqrt_t1;
+    let e3 = (f64::ln(s / i1) - drift * t1) / sqrt_t1;
+    let e4 = (f64::ln(i2 * i2 / (s * i1)) - drift * t1) / sqrt_t1;
+
+    let f1 = (f64::ln(s / h) + drift * t) / sqrt_t;
+    let f2 = (f64::ln(i2 * i2 / (s * h)) + drift * t) / sqrt_t;
+    let f3 = (f64::ln(i1 * i1 / (s * h)) + drift * t) / sqrt_t;
+    let f4 = (f64::ln(s * i1 * i1 / (h * i2 * i2)) + drift * t) / sqrt_t;
+
+    let rho = f64::sqrt(t1 / t);
+    let lambda = -r + gamma * b + 0.5 * gamma * (gamma - 1.0) * sigma.powi(2);
+    let kappa = 2.0 * b / sigma.powi(2) + 2.0 * gamma - 1.0;
+
+    f64::exp(lambda * t) * s.powf(gamma)
+        * (bivariate_normal_cdf(-e1, -f1, rho)
+            - (i2 / s).powf(kappa) * bivariate_normal_cdf(-e2, -f2, rho)
+            - (i1 / s).powf(kappa) * bivariate_normal_cdf(-e3, -f3, -rho)
+            + (i1 / i2).powf(kappa) * bivariate_normal_cdf(-e4, -f4, -rho))
+}
+
+impl OptionPricingModel for AmericanApproximationModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        // Without dividends an American call is never exercised early, and without interest
+        // neither is an American put
+        let never_exercised = match option_type {
+            OptionType::Call => self.dividend_yield <= 0.0,
+            OptionType::Put => self.risk_free_rate <= 0.0,
+        };
+        if never_exercised {
+            return self.european_price(self.underlying, option_type);
+        }
+
+        match self.method {
+            AmericanApproximation::BaroneAdesiWhaley => self.barone_adesi_whaley(option_type),
+            AmericanApproximation::BjerksundStensland => self.bjerksund_stensland(option_type),
+            AmericanApproximation::JuZhong => self.ju_zhong(option_type),
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinomialModel, BinomialTree, ConvergenceAcceleration, ExerciseStyle, Payoff};
+
+    #[test]
+    fn puts_without_dividends_match_american_binomial() {
+        for underlying in [90.0, 100.0, 120.0] {
+            let binomial = BinomialModel {
+                underlying,
+                strike: 100.0,
+                maturity: 1.0,
+                volatility: 0.2,
+                risk_free_rate: 0.05,
+                steps: 2001,
+                tree: BinomialTree::CoxRossRubinstein,
+                exercise: ExerciseStyle::American,
+                acceleration: ConvergenceAcceleration::BlackScholesRichardson,
+                payoff: Payoff::Vanilla,
+            };
+            let expected = binomial.price(OptionType::Put);
+
+            let methods = [
+                (AmericanApproximation::BaroneAdesiWhaley, 0.1),
+                (AmericanApproximation::BjerksundStensland, 0.1),
+                (AmericanApproximation::JuZhong, 0.03),
+            ];
+            for (method, tolerance) in methods {
+                let model = AmericanApproximationModel {
+                    underlying,
+                    strike: 100.0,
+                    maturity: 1.0,
+                    volatility: 0.2,
+                    risk_free_rate: 0.05,
+                    dividend_yield: 0.0,
+                    method,
+                };
+                let price = model.price(OptionType::Put);
+                assert!((price - expected).abs() < tolerance, "S={} {:?}: {} vs {}", underlying, method, price, expected);
+            }
+        }
+    }
+
+    // American call on a CRR tree with cost of carry r - q
+    fn american_call_tree(underlying: f64, risk_free_rate: f64, dividend_yield: f64, steps: usize) -> f64 {
+        let (strike, maturity, volatility) = (100.0, 1.0, 0.2);
+        let dt = maturity / steps as f64;
+        let u = f64::exp(volatility * dt.sqrt());
+        let p = (f64::exp((risk_free_rate - dividend_yield) * dt) - 1.0 / u) / (u - 1.0 / u);
+        let discount = f64::exp(-risk_free_rate * dt);
+        let asset_price = |step: usize, i: usize| underlying * u.powi(2 * i as i32 - step as i32);
+
+        let mut values: Vec<f64> = (0..=steps).map(|i| f64::max(asset_price(steps, i) - strike, 0.0)).collect();
+        for step in (0..steps).rev() {
+            for i in 0..=step {
+                let continuation = discount * (p * values[i + 1] + (1.0 - p) * values[i]);
+                values[i] = f64::max(continuation, asset_price(step, i) - strike);
+            }
+        }
+        values[0]
+    }
+
+    #[test]
+    fn dividend_paying_calls_match_american_tree() {
+        // Including r = 0, where h = 1 - e^(-rT) vanishes
+        for (risk_free_rate, dividend_yield) in [(0.05, 0.08), (0.0, 0.03)] {
+            for underlying in [90.0, 100.0, 120.0] {
+                let expected = american_call_tree(underlying, risk_free_rate, dividend_yield, 2000);
+                let methods = [
+                    (AmericanApproximation::BaroneAdesiWhaley, 0.15),
+                    (AmericanApproximation::BjerksundStensland, 0.1),
+                    (AmericanApproximation::JuZhong, 0.03),
+                ];
+                for (method, tolerance) in methods {
+                    let model = AmericanApproximationModel {
+                        underlying,
+                        strike: 100.0,
+                        maturity: 1.0,
+                        volatility: 0.2,
+                        risk_free_rate,
+                        dividend_yield,
+                        method,
+                    };
+                    let price = model.price(OptionType::Call);
+                    assert!((price - expected).abs() < tolerance, "r={} S={} {:?}: {} vs {}", risk_free_rate, underlying, method, price, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_rate_calls_match_put_call_symmetry() {
+        // McDonald-Schroder: C(S, K, r, q) = P(K, S, q, r), a put without dividends when r = 0
+        let binomial = BinomialModel {
+            underlying: 100.0,
+            strike: 110.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.03,
+            steps: 2001,
+            tree: BinomialTree::CoxRossRubinstein,
+            exercise: ExerciseStyle::American,
+            acceleration: ConvergenceAcceleration::BlackScholesRichardson,
+            payoff: Payoff::Vanilla,
+        };
+        let expected = binomial.price(OptionType::Put);
+
+        for method in [AmericanApproximation::BaroneAdesiWhaley, AmericanApproximation::BjerksundStensland, AmericanApproximation::JuZhong] {
+            let model = AmericanApproximationModel {
+                underlying: 110.0,
+                strike: 100.0,
+                maturity: 1.0,
+                volatility: 0.2,
+                risk_free_rate: 0.0,
+                dividend_yield: 0.03,
+                method,
+            };
+            let price = model.price(OptionType::Call);
+            assert!((price - expected).abs() < 0.1, "{:?}: {} vs {}", method, price, expected);
+            assert!(model.price(OptionType::Put).is_finite());
+        }
+    }
+}
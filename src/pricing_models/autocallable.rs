@@ -0,0 +1,194 @@
+// Autocallable notes and barrier reverse convertibles, priced on simulated paths by
+// `MonteCarloModel::autocallable_report` (single underlying, any dynamics) and
+// `MultiAssetMonteCarloModel::autocallable_report` (worst-of several correlated underlyings).
+// Barriers and strikes are levels of the performance S(t)/S(0), worst-of across the underlyings.
+
+use std::fmt;
+
+// Observation dates rejected by the Monte Carlo reports
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ScheduleError {
+    Empty,
+    // The observation date at this index is not after the previous one
+    NotIncreasing { index: usize },
+    // An observation date is today or in the past
+    NotPositive { time: f64 },
+    // An observation date falls after the model's maturity
+    AfterMaturity { time: f64, maturity: f64 },
+    // The observation date at this index rounds to the same simulation step as the previous one,
+    // or to today for the first date; more steps are needed to tell them apart
+    SameStep { index: usize },
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScheduleError::Empty => write!(f, "no observation dates"),
+            ScheduleError::NotIncreasing { index } =>
+                write!(f, "observation date {} is not after the previous one", index),
+            ScheduleError::NotPositive { time } => write!(f, "observation date {} is not after today", time),
+            ScheduleError::AfterMaturity { time, maturity } =>
+                write!(f, "observation date {} is after the maturity {}", time, maturity),
+            ScheduleError::SameStep { index } =>
+                write!(f, "observation date {} falls on the same simulation step as the previous one", index),
+        }
+    }
+}
+
+impl std::error::Error for ScheduleError {}
+
+// Checks that the observation dates are non-empty, increasing, after today and no later than the
+// maturity, and maps each to the nearest of `steps` equal time steps to maturity. Dates that
+// would share a step are rejected rather than merged.
+pub(crate) fn observation_steps(times: &[f64], maturity: f64, steps: u32) -> Result<Vec<usize>, ScheduleError> {
+    if times.is_empty() {
+        return Err(ScheduleError::Empty);
+    }
+    if let Some(index) = (1..times.len()).find(|&i| times[i] <= times[i - 1]) {
+        return Err(ScheduleError::NotIncreasing { index });
+    }
+    if let Some(&time) = times.iter().find(|&&t| t <= 0.0) {
+        return Err(ScheduleError::NotPositive { time });
+    }
+    if let Some(&time) = times.iter().find(|&&t| t > maturity) {
+        return Err(ScheduleError::AfterMaturity { time, maturity });
+    }
+
+    let observation_steps: Vec<usize> =
+        times.iter().map(|t| ((t / maturity * steps as f64).round() as usize).min(steps as usize)).collect();
+    // Step 0 is today
+    if let Some(index) = (0..observation_steps.len())
+        .find(|&i| observation_steps[i] == if i == 0 { 0 } else { observation_steps[i - 1] })
+    {
+        return Err(ScheduleError::SameStep { index });
+    }
+
+    Ok(observation_steps)
+}
+
+// Monitoring of the knock-in barrier of the put sold at maturity
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum KnockInMonitoring {
+    // Breached if the final performance is below the barrier
+    European,
+    // Breached if the performance is below the barrier at any simulation step
+    Continuous,
+}
+
+// Terms of an autocallable. At each observation date a coupon is paid if the performance is at or
+// above the coupon barrier (with the coupons missed since the last payment if `memory` is set),
+// and before maturity the note redeems at par if it is at or above the autocall barrier. At
+// maturity the note redeems at par, less a put notional × max(1 - performance / put strike, 0)
+// if the knock-in barrier was breached. A barrier reverse convertible is an autocallable with an
+// infinite autocall barrier and a zero coupon barrier.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Autocallable {
+    pub observation_times: Vec<f64>, // Increasing observation dates in years, the last at the model's maturity
+    pub autocall_barrier: f64,
+    pub coupon_barrier: f64,
+    pub coupon: f64, // Coupon per observation as a fraction of the notional
+    pub memory: bool,
+    pub knock_in_barrier: f64,
+    pub knock_in_monitoring: KnockInMonitoring,
+    pub put_strike: f64,
+    pub notional: f64,
+}
+
+// Outcome of one simulated path
+pub(crate) struct Settlement {
+    value: f64,          // Discounted cash flows
+    observation: usize,  // Index of the observation date at which the note redeemed
+    knocked_in: bool,    // Reached maturity with the put knocked in
+}
+
+impl Autocallable {
+    // Observation dates as indices into a path with `steps` equal steps to `maturity`
+    pub(crate) fn observation_steps(&self, maturity: f64, steps: u32) -> Result<Vec<usize>, ScheduleError> {
+        observation_steps(&self.observation_times, maturity, steps)
+    }
+
+    // Cash flows along a path of performances at each simulation step, starting with today
+    pub(crate) fn settle(&self, performances: &[f64], observation_steps: &[usize], risk_free_rate: f64) -> Settlement {
+        let last = observation_steps.len() - 1;
+        let mut value = 0.0;
+        let mut missed_coupons = 0.0;
+
+        for (observation, &step) in observation_steps.iter().enumerate() {
+            let performance = performances[step];
+            let discount = f64::exp(-risk_free_rate * self.observation_times[observation]);
+
+            if performance >= self.coupon_barrier {
+                value += self.notional * self.coupon * (1.0 + missed_coupons) * discount;
+                missed_coupons = 0.0;
+            } else if self.memory {
+                missed_coupons += 1.0;
+            }
+
+            if observation < last && performance >= self.autocall_barrier {
+                return Settlement { value: value + self.notional * discount, observation, knocked_in: false };
+            }
+        }
+
+        let final_performance = performances[observation_steps[last]];
+        let knocked_in = match self.knock_in_monitoring {
+            KnockInMonitoring::European => final_performance < self.knock_in_barrier,
+            KnockInMonitoring::Continuous =>
+                performances[..=observation_steps[last]].iter().any(|&p| p < self.knock_in_barrier),
+        };
+        let redemption = if knocked_in {
+            self.notional * f64::min(final_performance / self.put_strike, 1.0)
+        } else {
+            self.notional
+        };
+
+        Settlement {
+            value: value + redemption * f64::exp(-risk_free_rate * self.observation_times[last]),
+            observation: last,
+            knocked_in,
+        }
+    }
+}
+
+// Price of an autocallable with the distribution of its redemption date
+#[derive(PartialEq, Debug, Clone)]
+pub struct AutocallableReport {
+    pub price: f64,
+    pub autocall_probabilities: Vec<f64>, // Probability of redeeming at each observation date; the last entry is reaching maturity
+    pub knock_in_probability: f64,        // Probability of reaching maturity with the put knocked in
+    pub expected_life: f64,               // Expected time to redemption in years
+}
+
+impl AutocallableReport {
+    pub(crate) fn from_settlements(product: &Autocallable, settlements: &[Settlement]) -> Self {
+        let simulations = settlements.len() as f64;
+        let mut redemptions = vec![0; product.observation_times.len()];
+        for settlement in settlements {
+            redemptions[settlement.observation] += 1;
+        }
+        let autocall_probabilities: Vec<f64> = redemptions.iter().map(|&count| count as f64 / simulations).collect();
+
+        AutocallableReport {
+            price: settlements.iter().map(|s| s.value).sum::<f64>() / simulations,
+            knock_in_probability: settlements.iter().filter(|s| s.knocked_in).count() as f64 / simulations,
+            expected_life: autocall_probabilities.iter().zip(&product.observation_times).map(|(p, t)| p * t).sum(),
+            autocall_probabilities,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_schedules_are_rejected() {
+        assert_eq!(observation_steps(&[], 1.0, 12), Err(ScheduleError::Empty));
+        assert_eq!(observation_steps(&[0.5, 0.5, 1.0], 1.0, 12), Err(ScheduleError::NotIncreasing { index: 1 }));
+        assert_eq!(observation_steps(&[0.5, 1.5], 1.0, 12), Err(ScheduleError::AfterMaturity { time: 1.5, maturity: 1.0 }));
+        assert_eq!(observation_steps(&[0.0, 0.5, 1.0], 1.0, 12), Err(ScheduleError::NotPositive { time: 0.0 }));
+        assert_eq!(observation_steps(&[-0.5, 1.0], 1.0, 12), Err(ScheduleError::NotPositive { time: -0.5 }));
+        assert_eq!(observation_steps(&[0.5, 0.52, 1.0], 1.0, 12), Err(ScheduleError::SameStep { index: 1 }));
+        assert_eq!(observation_steps(&[0.01, 1.0], 1.0, 12), Err(ScheduleError::SameStep { index: 0 }));
+        assert_eq!(observation_steps(&[0.25, 0.5, 1.0], 1.0, 12), Ok(vec![3, 6, 12]));
+    }
+}
@@ -0,0 +1,350 @@
+use crate::{OptionPricingModel, OptionType, MonteCarloModel, Dynamics, Payoff};
+use crate::pricing_models::lattice::option_payoff;
+use crate::pricing_models::finite_difference::solve_tridiagonal;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+// Whether triggering the barrier cancels the option or activates it
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum BarrierKnock {
+    Out,
+    In,
+}
+
+// Barrier triggered once the underlying has stayed outside the corridor [lower, upper] for
+// `window` years without interruption (a Parisian barrier), or as soon as it leaves the corridor
+// when the window is zero. A zero lower or infinite upper barrier gives a single barrier.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct ParisianBarrier {
+    pub lower_barrier: f64,
+    pub upper_barrier: f64,
+    pub window: f64,
+    pub knock: BarrierKnock,
+}
+
+impl ParisianBarrier {
+    pub(crate) fn is_outside(&self, asset_price: f64) -> bool {
+        asset_price < self.lower_barrier || asset_price > self.upper_barrier
+    }
+
+    // Number of consecutive observations `dt` apart outside the corridor that trigger the barrier
+    pub(crate) fn trigger_count(&self, dt: f64) -> usize {
+        usize::max((self.window / dt).round() as usize, 1)
+    }
+}
+
+// Numerical method for options with a Parisian or double barrier
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ParisianMethod {
+    // GBM paths from `MonteCarloModel` with the barrier monitored at each step. The seed is
+    // reused for bumped greeks so they share random numbers.
+    MonteCarlo { simulations: u32, steps: u32, seed: u64 },
+    // Implicit finite differences on layers of the asset price grid, one for each number of time
+    // steps spent outside the corridor in the current excursion (Haber, Schönbucher and Wilmott 1999)
+    FiniteDifference { price_steps: usize, time_steps: usize },
+}
+
+// European option with a Parisian or double barrier, monitored discretely at the steps of the
+// chosen method
+#[derive(Clone)]
+pub struct ParisianOptionModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+    pub barrier: ParisianBarrier,
+    pub method: ParisianMethod,
+}
+
+// Solution of the PDE on the price grid at t = 0 and one time step later
+struct GridSolution {
+    values: Vec<f64>,
+    next_values: Vec<f64>,
+    ds: f64,
+    dt: f64,
+    node: usize, // Index of the current underlying price on the grid
+}
+
+impl ParisianOptionModel {
+    // Width of the grid above the largest of S, K and a finite upper barrier, in standard
+    // deviations of ln S_T
+    const GRID_WIDTH: f64 = 5.0;
+
+    fn monte_carlo_price(&self, option_type: OptionType, simulations: u32, steps: u32, seed: u64) -> f64 {
+        let model = MonteCarloModel {
+            underlying: self.underlying,
+            strike: self.strike,
+            maturity: self.maturity,
+            volatility: self.volatility,
+            risk_free_rate: self.risk_free_rate,
+            dividend_yield: self.dividend_yield,
+            simulations,
+            steps,
+            dynamics: Dynamics::GeometricBrownian,
+            payoff: Payoff::Vanilla,
+        };
+        model.barrier_price_with_rng(&self.barrier, option_type, &mut StdRng::seed_from_u64(seed))
+    }
+
+    // Grid spacing chosen so that the current underlying price falls exactly on a node
+    fn grid(&self, price_steps: usize) -> (f64, usize) {
+        let price_steps = price_steps.max(2);
+        let upper = if self.barrier.upper_barrier.is_finite() { self.barrier.upper_barrier } else { 0.0 };
+        let s_max = f64::max(f64::max(self.underlying, self.strike), upper)
+            * f64::exp(Self::GRID_WIDTH * self.volatility * f64::sqrt(self.maturity));
+        let node = ((price_steps as f64 * self.underlying / s_max).round() as usize).clamp(1, price_steps - 1);
+        (self.underlying / node as f64, node)
+    }
+
+    // Knock-out values on the grid, or vanilla values when `barrier` is None
+    fn solve_layers(&self, option_type: OptionType, barrier: Option<&ParisianBarrier>, grid: (f64, usize),
+                    price_steps: usize, time_steps: usize) -> GridSolution {
+        let (ds, node) = grid;
+        let m = price_steps;
+        let dt = self.maturity / time_steps as f64;
+        let (sigma2, r, q) = (self.volatility.powi(2), self.risk_free_rate, self.dividend_yield);
+
+        let outside: Vec<bool> = (0..=m).map(|i| barrier.is_some_and(|b| b.is_outside(i as f64 * ds))).collect();
+        let layers = barrier.map_or(1, |b| b.trigger_count(dt));
+
+        // Implicit Euler (I - ΔτL) V^(n+1) = V^n over the interior nodes, the same at every step
+        let mut lower = vec![0.0; m - 1];
+        let mut diagonal = vec![0.0; m - 1];
+        let mut upper = vec![0.0; m - 1];
+        for i in 1..m {
+            let x = i as f64;
+            lower[i - 1] = -0.5 * dt * (sigma2 * x * x - (r - q) * x);
+            diagonal[i - 1] = 1.0 + dt * (sigma2 * x * x + r);
+            upper[i - 1] = -0.5 * dt * (sigma2 * x * x + (r - q) * x);
+        }
+
+        // values[j][i]: value at node i after j steps outside the corridor in the current excursion
+        let payoff: Vec<f64> = (0..=m).map(|i| option_payoff(self.strike, i as f64 * ds, option_type)).collect();
+        let mut values = vec![payoff; layers];
+        let mut next_values = values[0].clone();
+
+        for step in 0..time_steps {
+            let tau = (step + 1) as f64 * dt;
+            next_values.clone_from(&values[0]);
+
+            // Dirichlet values at S = 0 and S_max: vanilla asymptotes inside the corridor, zero outside
+            let (vanilla_low, vanilla_high) = match option_type {
+                OptionType::Call => (0.0, m as f64 * ds * f64::exp(-q * tau) - self.strike * f64::exp(-r * tau)),
+                OptionType::Put => (self.strike * f64::exp(-r * tau), 0.0),
+            };
+            let low = if outside[0] { 0.0 } else { vanilla_low };
+            let high = if outside[m] { 0.0 } else { vanilla_high };
+
+            values = (0..layers)
+                .map(|j| {
+                    // One step later the excursion has grown by a step outside the corridor and
+                    // restarted inside it, and the option is knocked out when it fills the window
+                    let mut rhs: Vec<f64> = (1..m)
+                        .map(|i| match (outside[i], j + 1 < layers) {
+                            (false, _) => values[0][i],
+                            (true, true) => values[j + 1][i],
+                            (true, false) => 0.0,
+                        })
+                        .collect();
+                    rhs[0] -= lower[0] * low;
+                    rhs[m - 2] -= upper[m - 2] * high;
+
+                    let mut layer = vec![low];
+                    layer.extend(solve_tridiagonal(&lower, &diagonal, &upper, &rhs));
+                    layer.push(high);
+                    layer
+                })
+                .collect();
+        }
+
+        GridSolution { values: values.swap_remove(0), next_values, ds, dt, node }
+    }
+
+    // Knock-in values are vanilla values less knock-out values on the same grid. Grids have at
+    // least two price intervals and one time step.
+    fn solve(&self, option_type: OptionType, grid: (f64, usize), price_steps: usize, time_steps: usize) -> GridSolution {
+        let (price_steps, time_steps) = (price_steps.max(2), time_steps.max(1));
+        let knock_out = self.solve_layers(option_type, Some(&self.barrier), grid, price_steps, time_steps);
+        match self.barrier.knock {
+            BarrierKnock::Out => knock_out,
+            BarrierKnock::In => {
+                let vanilla = self.solve_layers(option_type, None, grid, price_steps, time_steps);
+                let difference = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(a, b)| a - b).collect();
+                GridSolution {
+                    values: difference(&vanilla.values, &knock_out.values),
+                    next_values: difference(&vanilla.next_values, &knock_out.next_values),
+                    ..knock_out
+                }
+            }
+        }
+    }
+
+    // Bump to the underlying for Monte Carlo delta and gamma, large enough that the barrier
+    // discontinuity does not swamp the difference
+    fn monte_carlo_epsilon(&self) -> f64 {
+        0.01 * self.underlying
+    }
+
+    fn bumped_underlying_price(&self, option_type: OptionType, epsilon: f64) -> f64 {
+        let mut model = self.clone();
+        model.underlying += epsilon;
+        model.price(option_type)
+    }
+}
+
+// Monte Carlo greeks are bumped with common random numbers; finite difference delta, gamma and
+// theta are read off the grid and vega is bumped on the same grid
+impl OptionPricingModel for ParisianOptionModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        match self.method {
+            ParisianMethod::MonteCarlo { simulations, steps, seed } =>
+                self.monte_carlo_price(option_type, simulations, steps, seed),
+            ParisianMethod::FiniteDifference { price_steps, time_steps } => {
+                let solution = self.solve(option_type, self.grid(price_steps), price_steps, time_steps);
+                solution.values[solution.node]
+            }
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        match self.method {
+            ParisianMethod::MonteCarlo { .. } => {
+                let epsilon = self.monte_carlo_epsilon();
+                (self.bumped_underlying_price(option_type, epsilon) - self.bumped_underlying_price(option_type, -epsilon))
+                    / (2.0 * epsilon)
+            }
+            ParisianMethod::FiniteDifference { price_steps, time_steps } => {
+                let solution = self.solve(option_type, self.grid(price_steps), price_steps, time_steps);
+                let i = solution.node;
+                (solution.values[i + 1] - solution.values[i - 1]) / (2.0 * solution.ds)
+            }
+        }
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        match self.method {
+            ParisianMethod::MonteCarlo { .. } => {
+                let epsilon = self.monte_carlo_epsilon();
+                (self.bumped_underlying_price(option_type, epsilon) - 2.0 * self.price(option_type)
+                    + self.bumped_underlying_price(option_type, -epsilon))
+                    / (epsilon * epsilon)
+            }
+            ParisianMethod::FiniteDifference { price_steps, time_steps } => {
+                let solution = self.solve(option_type, self.grid(price_steps), price_steps, time_steps);
+                let i = solution.node;
+                (solution.values[i + 1] - 2.0 * solution.values[i] + solution.values[i - 1]) / solution.ds.powi(2)
+            }
+        }
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        match self.method {
+            // Keep the number of steps so each path draws the same random numbers from the seed
+            ParisianMethod::MonteCarlo { .. } => {
+                let epsilon = 0.01;
+                let price = self.price(option_type);
+
+                let mut model_down = self.clone();
+                model_down.maturity -= epsilon;
+                let price_down = model_down.price(option_type);
+
+                (price_down - price) / epsilon
+            }
+            // Difference between the last two time levels of the grid
+            ParisianMethod::FiniteDifference { price_steps, time_steps } => {
+                let solution = self.solve(option_type, self.grid(price_steps), price_steps, time_steps);
+                let i = solution.node;
+                (solution.next_values[i] - solution.values[i]) / solution.dt
+            }
+        }
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = match self.method {
+            ParisianMethod::MonteCarlo { .. } => model_up.price(option_type),
+            // Keep the grid so the barriers do not move relative to the nodes
+            ParisianMethod::FiniteDifference { price_steps, time_steps } => {
+                let solution = model_up.solve(option_type, self.grid(price_steps), price_steps, time_steps);
+                solution.values[solution.node]
+            }
+        };
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlackScholesModel;
+
+    fn parisian_model(method: ParisianMethod) -> ParisianOptionModel {
+        ParisianOptionModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.03,
+            barrier: ParisianBarrier { lower_barrier: 0.0, upper_barrier: 140.0, window: 0.1, knock: BarrierKnock::Out },
+            method,
+        }
+    }
+
+    #[test]
+    fn without_barrier_matches_black_scholes_with_dividends() {
+        let barrier = ParisianBarrier { lower_barrier: 0.0, upper_barrier: f64::INFINITY, window: 0.0, knock: BarrierKnock::Out };
+        let finite_difference = ParisianOptionModel {
+            barrier,
+            ..parisian_model(ParisianMethod::FiniteDifference { price_steps: 800, time_steps: 500 })
+        };
+        let monte_carlo = ParisianOptionModel {
+            barrier,
+            ..parisian_model(ParisianMethod::MonteCarlo { simulations: 200_000, steps: 1, seed: 42 })
+        };
+        let black_scholes = BlackScholesModel {
+            underlying: 100.0 * f64::exp(-0.03),
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+        };
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let price = black_scholes.price(option_type);
+            assert!((finite_difference.price(option_type) - price).abs() < 0.02);
+            assert!((monte_carlo.price(option_type) - price).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn tiny_grids_do_not_panic() {
+        let models = [
+            parisian_model(ParisianMethod::FiniteDifference { price_steps: 0, time_steps: 0 }),
+            parisian_model(ParisianMethod::FiniteDifference { price_steps: 1, time_steps: 1 }),
+            parisian_model(ParisianMethod::MonteCarlo { simulations: 100, steps: 1, seed: 42 }),
+        ];
+        for model in &models {
+            for option_type in [OptionType::Call, OptionType::Put] {
+                assert!(model.price(option_type).is_finite());
+                assert!(model.theta(option_type).is_finite());
+            }
+        }
+    }
+}
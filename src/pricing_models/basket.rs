@@ -0,0 +1,297 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+use crate::pricing_models::multi_asset_monte_carlo::{check_dimensions, check_correlation, MultiAssetError};
+
+// Analytic approximation of the arithmetic basket distribution
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum BasketApproximation {
+    // Levy (1992): lognormal matching the first two moments of the basket
+    Levy,
+    // Ju (2002): Levy's lognormal plus a correction from a Taylor expansion of the ratio of
+    // characteristic functions in the volatility scale, to sixth order
+    Ju,
+}
+
+// European call or put on the weighted sum Σ w_i S_i of correlated GBM assets with continuous
+// dividend yields
+#[derive(Clone)]
+pub struct BasketModel {
+    pub underlyings: Vec<f64>,
+    pub weights: Vec<f64>,
+    pub volatilities: Vec<f64>,
+    pub dividend_yields: Vec<f64>,
+    pub correlation: Vec<Vec<f64>>,
+    pub strike: f64,
+    pub maturity: f64,
+    pub risk_free_rate: f64,
+    pub method: BasketApproximation,
+}
+
+impl BasketModel {
+    // Checks that the weights, volatilities, dividend yields and correlation matrix all have one
+    // entry per underlying and that the correlation matrix is a valid correlation matrix
+    pub fn validate(&self) -> Result<(), MultiAssetError> {
+        let inputs = [
+            ("weights", self.weights.len()),
+            ("volatilities", self.volatilities.len()),
+            ("dividend_yields", self.dividend_yields.len()),
+        ];
+        check_dimensions(self.underlyings.len(), &inputs, &self.correlation)?;
+        Ok(check_correlation(&self.correlation)?)
+    }
+
+    // Forward value of the basket: U1 = Σ w_i S_i e^((r - q_i)T)
+    fn forward(&self) -> f64 {
+        self.weighted_forwards().iter().sum()
+    }
+
+    fn weighted_forwards(&self) -> Vec<f64> {
+        (0..self.underlyings.len())
+            .map(|i| {
+                self.weights[i] * self.underlyings[i]
+                    * f64::exp((self.risk_free_rate - self.dividend_yields[i]) * self.maturity)
+            })
+            .collect()
+    }
+
+    // Terminal log-covariance S_ij = ρ_ij σ_i σ_j T
+    fn covariance(&self) -> Vec<Vec<f64>> {
+        let n = self.underlyings.len();
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| self.correlation[i][j] * self.volatilities[i] * self.volatilities[j] * self.maturity)
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Variance of the moment-matched log basket: v = ln(E[B²] / E[B]²)
+    fn lognormal_variance(&self, shares: &[f64], covariance: &[Vec<f64>]) -> f64 {
+        let n = shares.len();
+        let second_moment: f64 = (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .map(|(i, j)| shares[i] * shares[j] * f64::exp(covariance[i][j]))
+            .sum();
+        second_moment.ln()
+    }
+
+    fn levy_price(&self, variance: f64, option_type: OptionType) -> f64 {
+        // Black's formula on the basket forward, written as Black-Scholes on the discounted forward
+        BlackScholesModel {
+            underlying: self.forward() * f64::exp(-self.risk_free_rate * self.maturity),
+            strike: self.strike,
+            maturity: self.maturity,
+            volatility: f64::sqrt(variance / self.maturity),
+            risk_free_rate: self.risk_free_rate,
+        }
+        .price(option_type)
+    }
+
+    // Ju's correction. With π_i = w_i F_i / U1, the moments E[(B/U1)^s] of the basket with
+    // volatilities scaled by z expand in z² with coefficients polynomial in s. Dividing by the
+    // lognormal's moments leaves, to order z⁶, s(s - 1)(s - 2)(α + βs), which inverts to
+    // K e^(-rT) [-2α p(y) - (α - 2β) p'(y) + β p''(y)] at y = ln K, where p is the lognormal's
+    // log density.
+    fn ju_correction(&self, shares: &[f64], covariance: &[Vec<f64>], variance: f64) -> f64 {
+        let n = shares.len();
+        let pairs = || (0..n).flat_map(|i| (0..n).map(move |j| (i, j)));
+
+        // u = Sπ and the π-weighted sums of products of S over connected index graphs
+        let u: Vec<f64> = (0..n).map(|i| (0..n).map(|j| covariance[i][j] * shares[j]).sum()).collect();
+        let mean: f64 = (0..n).map(|i| shares[i] * u[i]).sum();
+        let double_edge: f64 = pairs().map(|(i, j)| shares[i] * shares[j] * covariance[i][j].powi(2)).sum();
+        let two_path: f64 = (0..n).map(|i| shares[i] * u[i].powi(2)).sum();
+        let double_edge_branch: f64 = pairs()
+            .map(|(i, j)| shares[i] * shares[j] * covariance[i][j].powi(2) * u[i])
+            .sum();
+        let three_path: f64 = pairs().map(|(i, j)| shares[i] * shares[j] * u[i] * covariance[i][j] * u[j]).sum();
+        let star: f64 = (0..n).map(|i| shares[i] * u[i].powi(3)).sum();
+        let triangle: f64 = pairs()
+            .map(|(i, j)| {
+                let closing: f64 = (0..n).map(|k| shares[k] * covariance[j][k] * covariance[i][k]).sum();
+                shares[i] * shares[j] * covariance[i][j] * closing
+            })
+            .sum();
+
+        // Joint cumulants of S over the index graphs
+        let kappa_two_path = two_path - mean.powi(2);
+        let kappa_double_edge_branch = double_edge_branch - double_edge * mean - 2.0 * two_path * mean + 2.0 * mean.powi(3);
+        let kappa_three_path = three_path - 2.0 * two_path * mean + mean.powi(3);
+        let kappa_star = star - 3.0 * two_path * mean + 2.0 * mean.powi(3);
+        let kappa_triangle = triangle - 3.0 * two_path * mean + 2.0 * mean.powi(3);
+
+        let alpha = 0.5 * kappa_two_path
+            + (3.0 * kappa_double_edge_branch - 9.0 * kappa_three_path - 3.0 * kappa_star + kappa_triangle) / 6.0;
+        let beta = (3.0 * kappa_three_path + kappa_star) / 6.0;
+
+        // Lognormal log density N(m, v) and its derivatives at y = ln K
+        let m = self.forward().ln() - 0.5 * variance;
+        let y = self.strike.ln() - m;
+        let density = f64::exp(-y * y / (2.0 * variance)) / f64::sqrt(2.0 * std::f64::consts::PI * variance);
+        let density_prime = -y / variance * density;
+        let density_second = (y * y / variance.powi(2) - 1.0 / variance) * density;
+
+        self.strike * f64::exp(-self.risk_free_rate * self.maturity)
+            * (-2.0 * alpha * density - (alpha - 2.0 * beta) * density_prime + beta * density_second)
+    }
+
+    // Delta to the i-th underlying
+    pub fn asset_delta(&self, asset: usize, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlyings[asset] += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlyings[asset] -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+}
+
+// Greeks from the trait bump every underlying or volatility together. Pricing panics if the
+// inputs fail `validate`.
+impl OptionPricingModel for BasketModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+        let forward = self.forward();
+        let shares: Vec<f64> = self.weighted_forwards().iter().map(|f| f / forward).collect();
+        let covariance = self.covariance();
+        let variance = self.lognormal_variance(&shares, &covariance);
+
+        let levy = self.levy_price(variance, option_type);
+        match self.method {
+            BasketApproximation::Levy => levy,
+            // The correction leaves the forward unchanged, so it is the same for calls and puts
+            BasketApproximation::Ju => levy + self.ju_correction(&shares, &covariance, variance),
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlyings.iter_mut().for_each(|s| *s += epsilon);
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlyings.iter_mut().for_each(|s| *s -= epsilon);
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlyings.iter_mut().for_each(|s| *s += epsilon);
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlyings.iter_mut().for_each(|s| *s -= epsilon);
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatilities.iter_mut().for_each(|sigma| *sigma += epsilon);
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiAssetMonteCarloModel, MultiAssetPayoff, DimensionError, CorrelationError};
+
+    fn basket_model() -> BasketModel {
+        BasketModel {
+            underlyings: vec![100.0, 100.0],
+            weights: vec![0.5, 0.5],
+            volatilities: vec![0.2, 0.3],
+            dividend_yields: vec![0.0, 0.02],
+            correlation: vec![vec![1.0, 0.5], vec![0.5, 1.0]],
+            strike: 100.0,
+            maturity: 1.0,
+            risk_free_rate: 0.05,
+            method: BasketApproximation::Ju,
+        }
+    }
+
+    #[test]
+    fn ju_matches_multi_asset_monte_carlo() {
+        let basket = basket_model();
+        let monte_carlo = MultiAssetMonteCarloModel {
+            underlyings: basket.underlyings.clone(),
+            volatilities: basket.volatilities.clone(),
+            dividend_yields: basket.dividend_yields.clone(),
+            correlation: basket.correlation.clone(),
+            strike: basket.strike,
+            maturity: basket.maturity,
+            risk_free_rate: basket.risk_free_rate,
+            simulations: 400_000,
+            payoff: MultiAssetPayoff::Basket { weights: basket.weights.clone() },
+            seed: 42,
+        };
+
+        // About three standard errors of the Monte Carlo price
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (basket.price(option_type), monte_carlo.price(option_type));
+            assert!((price - expected).abs() < 0.06, "{:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+
+    #[test]
+    fn mismatched_dimensions_are_rejected() {
+        let model = BasketModel { volatilities: vec![0.2], ..basket_model() };
+        assert_eq!(model.validate(), Err(DimensionError { input: "volatilities", expected: 2, found: 1 }.into()));
+
+        let model = BasketModel { correlation: vec![vec![1.0, 0.5], vec![0.5]], ..basket_model() };
+        assert_eq!(model.validate(), Err(DimensionError { input: "correlation row", expected: 2, found: 1 }.into()));
+        assert_eq!(basket_model().validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_correlation_matrices_are_rejected() {
+        let model = BasketModel { correlation: vec![vec![1.0, 0.5], vec![0.4, 1.0]], ..basket_model() };
+        assert_eq!(model.validate(), Err(CorrelationError::Asymmetric { row: 1, column: 0 }.into()));
+
+        let model = BasketModel { correlation: vec![vec![1.0, 0.5], vec![0.5, 0.9]], ..basket_model() };
+        assert_eq!(model.validate(), Err(CorrelationError::DiagonalNotOne { index: 1, value: 0.9 }.into()));
+
+        let model = BasketModel { correlation: vec![vec![1.0, 1.5], vec![1.5, 1.0]], ..basket_model() };
+        assert_eq!(model.validate(), Err(CorrelationError::OutOfRange { row: 1, column: 0, value: 1.5 }.into()));
+    }
+}
@@ -1,4 +1,42 @@
-use crate::{OptionPricingModel, OptionType};
+use crate::{OptionPricingModel, OptionType, ExerciseStyle, Payoff, BlackScholesModel, PowerOptionModel, GapOptionModel};
+use crate::pricing_models::lattice::{payoff_value, payoff_strike, node_value, CriticalPrice};
+
+// Choice of up/down factors and risk-neutral probability for the binomial tree
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum BinomialTree {
+    // Cox-Ross-Rubinstein: u = e^(σ√Δt), d = 1/u
+    CoxRossRubinstein,
+    // Jarrow-Rudd equal-probability tree: u, d = e^((r - σ²/2)Δt ± σ√Δt), p = 1/2
+    JarrowRudd,
+    // Tian (1993): matches the first three moments of the lognormal step
+    Tian,
+    // Leisen-Reimer (1996) with Peizer-Pratt inversion; uses an odd number of steps and
+    // converges at O(1/N²)
+    LeisenReimer,
+    // CRR with a drift e^(ηΔt), η = ln(K/S)/T, so the tree is centred on the strike
+    DriftAdjusted,
+}
+
+// Techniques for removing the odd-even oscillation of lattice prices in the number of steps
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ConvergenceAcceleration {
+    None,
+    // Two-point Richardson extrapolation: 2 P(N) - P(N/2)
+    Richardson,
+    // Binomial Black-Scholes (BBS): Black-Scholes values replace the tree at the penultimate step
+    BlackScholes,
+    // BBSR (Broadie-Detemple): Richardson extrapolation of two BBS prices
+    BlackScholesRichardson,
+}
+
+// Node values at the first three time steps of a tree, from which price and greeks are read
+struct TreeValues {
+    early_values: [Vec<f64>; 3], // early_values[step][i] for steps 0, 1 and 2
+    exercise_boundary: Vec<(f64, f64)>, // (time, critical asset price) for steps with early exercise
+    u: f64,
+    d: f64,
+    dt: f64,
+}
 
 #[derive(Clone)]
 pub struct BinomialModel {
@@ -8,86 +46,253 @@ pub struct BinomialModel {
     pub volatility: f64,
     pub risk_free_rate: f64,
     pub steps: u32,
+    pub tree: BinomialTree,
+    pub exercise: ExerciseStyle,
+    pub acceleration: ConvergenceAcceleration,
+    pub payoff: Payoff,
 }
 
 impl BinomialModel {
-    fn option_payoff(&self, asset_price: f64, option_type: OptionType) -> f64 {
-        match option_type {
-            OptionType::Call => f64::max(0.0, asset_price - self.strike),
-            OptionType::Put => f64::max(0.0, self.strike - asset_price),
+    // Number of steps actually used: at least three so the tree greeks exist even with a
+    // Black-Scholes penultimate step, and odd for Leisen-Reimer
+    fn tree_steps(&self, steps: u32) -> u32 {
+        let steps = u32::max(steps, 3);
+        if self.tree == BinomialTree::LeisenReimer && steps.is_multiple_of(2) {
+            steps + 1
+        } else {
+            steps
         }
     }
-}
 
-impl OptionPricingModel for BinomialModel {
-    fn price(&self, option_type: OptionType) -> f64 {
-        let dt = self.maturity / self.steps as f64; // Δt: Time step size
-        let u = f64::exp(self.volatility * f64::sqrt(dt)); // Up factor: u = e^(σ√Δt)
-        let d = 1.0 / u; // Down factor: d = 1 / u
-        let p = (f64::exp(self.risk_free_rate * dt) - d) / (u - d); // Risk-neutral probability
+    // Asset price at which the payoff is centred, used to place the Leisen-Reimer and
+    // drift-adjusted trees
+    fn payoff_strike(&self) -> f64 {
+        payoff_strike(self.payoff, self.strike)
+    }
+
+    // Closed-form European value of the payoff over the remaining time, for the Black-Scholes
+    // penultimate step
+    fn european_value(&self, asset_price: f64, maturity: f64, option_type: OptionType) -> f64 {
+        match self.payoff {
+            Payoff::Vanilla => BlackScholesModel {
+                underlying: asset_price,
+                strike: self.strike,
+                maturity,
+                volatility: self.volatility,
+                risk_free_rate: self.risk_free_rate,
+            }.price(option_type),
+            Payoff::Power { exponent } => PowerOptionModel {
+                underlying: asset_price,
+                strike: self.strike,
+                maturity,
+                volatility: self.volatility,
+                risk_free_rate: self.risk_free_rate,
+                dividend_yield: 0.0,
+                exponent,
+            }.price(option_type),
+            Payoff::Gap { trigger_strike } => GapOptionModel {
+                underlying: asset_price,
+                strike: self.strike,
+                trigger_strike,
+                maturity,
+                volatility: self.volatility,
+                risk_free_rate: self.risk_free_rate,
+                dividend_yield: 0.0,
+            }.price(option_type),
+        }
+    }
+
+    // Peizer-Pratt method 2 inversion, mapping a normal quantile z to a binomial probability
+    fn peizer_pratt(z: f64, n: u32) -> f64 {
+        let n = n as f64;
+        let exponent = -(z / (n + 1.0 / 3.0 + 0.1 / (n + 1.0))).powi(2) * (n + 1.0 / 6.0);
+        0.5 + z.signum() * 0.5 * f64::sqrt(1.0 - f64::exp(exponent))
+    }
+
+    // Up factor, down factor and risk-neutral up probability for a tree with n steps
+    fn tree_parameters(&self, n: u32) -> (f64, f64, f64) {
+        let dt = self.maturity / n as f64; // Δt: Time step size
+        let sigma = self.volatility;
+        let growth = f64::exp(self.risk_free_rate * dt);
+
+        match self.tree {
+            BinomialTree::CoxRossRubinstein => {
+                let u = f64::exp(sigma * f64::sqrt(dt)); // Up factor: u = e^(σ√Δt)
+                let d = 1.0 / u; // Down factor: d = 1 / u
+                (u, d, (growth - d) / (u - d)) // Risk-neutral probability
+            }
+            BinomialTree::JarrowRudd => {
+                let drift = (self.risk_free_rate - 0.5 * sigma.powi(2)) * dt;
+                (f64::exp(drift + sigma * f64::sqrt(dt)), f64::exp(drift - sigma * f64::sqrt(dt)), 0.5)
+            }
+            BinomialTree::Tian => {
+                let v = f64::exp(sigma.powi(2) * dt);
+                let root = f64::sqrt(v * v + 2.0 * v - 3.0);
+                let u = 0.5 * growth * v * (v + 1.0 + root);
+                let d = 0.5 * growth * v * (v + 1.0 - root);
+                (u, d, (growth - d) / (u - d))
+            }
+            BinomialTree::LeisenReimer => {
+                let d1 = (f64::ln(self.underlying / self.payoff_strike())
+                    + (self.risk_free_rate + 0.5 * sigma.powi(2)) * self.maturity)
+                    / (sigma * f64::sqrt(self.maturity));
+                let d2 = d1 - sigma * f64::sqrt(self.maturity);
+
+                let p = Self::peizer_pratt(d2, n);
+                let p_share = Self::peizer_pratt(d1, n);
+                let u = growth * p_share / p;
+                let d = (growth - p * u) / (1.0 - p);
+                (u, d, p)
+            }
+            BinomialTree::DriftAdjusted => {
+                let eta = f64::ln(self.payoff_strike() / self.underlying) / self.maturity;
+                let u = f64::exp(eta * dt + sigma * f64::sqrt(dt));
+                let d = f64::exp(eta * dt - sigma * f64::sqrt(dt));
+                (u, d, (growth - d) / (u - d))
+            }
+        }
+    }
+
+    // Backward induction through a tree with the given number of steps. With `black_scholes_step`
+    // the values at the penultimate step are Black-Scholes prices over the final Δt.
+    fn build_tree(&self, steps: u32, black_scholes_step: bool, option_type: OptionType) -> TreeValues {
+        let steps = self.tree_steps(steps);
+        let dt = self.maturity / steps as f64; // Δt: Time step size
+        let (u, d, p) = self.tree_parameters(steps);
+
+        // Price of the underlying asset at node (step, i) is S * u^i * d^(step-i)
+        let asset_price = |step: u32, i: u32| self.underlying * u.powi(i as i32) * d.powi((step - i) as i32);
 
         // Vector to store option values at each node
-        let mut option_values: Vec<f64> = vec![0.0; (self.steps + 1) as usize];
+        let mut option_values: Vec<f64> = vec![0.0; (steps + 1) as usize];
+
+        let mut exercise_boundary = Vec::new();
+        let mut critical_price = CriticalPrice::new(self.payoff, self.strike, option_type, self.exercise);
 
-        // Compute option values at maturity (step N)
-        for i in 0..=self.steps {
-            // Price of the underlying asset at node (N, i) is S * u^i * d^(N-i)
-            let asset_price_at_maturity = self.underlying * u.powi(i as i32) * d.powi((self.steps - i) as i32);
-            option_values[i as usize] = self.option_payoff(asset_price_at_maturity, option_type.clone());
+        let first_step = if black_scholes_step {
+            // Start at step N-1 with the European Black-Scholes value over the last Δt
+            for i in 0..steps {
+                let european_value = self.european_value(asset_price(steps - 1, i), dt, option_type);
+                option_values[i as usize] = node_value(european_value, self.payoff, self.strike,
+                                                       asset_price(steps - 1, i), option_type, self.exercise);
+                critical_price.record(option_values[i as usize], asset_price(steps - 1, i));
+            }
+            critical_price.push_step(&mut exercise_boundary, (steps - 1) as f64 * dt);
+            steps - 1
+        } else {
+            // Compute option values at maturity (step N)
+            for i in 0..=steps {
+                option_values[i as usize] = payoff_value(self.payoff, self.strike, asset_price(steps, i), option_type);
+            }
+            steps
+        };
+
+        // Keep the values of the first three steps for the greeks
+        let mut early_values: [Vec<f64>; 3] = Default::default();
+        if first_step <= 2 {
+            early_values[first_step as usize] = option_values[..=first_step as usize].to_vec();
         }
 
         // Traverse backward through the tree, starting from the last time step
-        for step in (0..self.steps).rev() {
+        for step in (0..first_step).rev() {
             for i in 0..=step {
                 // Option value at node (step, i) is the discounted value at the next step
-                option_values[i as usize] = (p * option_values[(i + 1) as usize] 
-                                            + (1.0 - p) * option_values[i as usize])
+                let continuation = (p * option_values[(i + 1) as usize] 
+                                    + (1.0 - p) * option_values[i as usize])
                     * f64::exp(-self.risk_free_rate * dt);
+
+                // American options may be exercised early
+                option_values[i as usize] = node_value(continuation, self.payoff, self.strike, asset_price(step, i), option_type, self.exercise);
+                critical_price.record(option_values[i as usize], asset_price(step, i));
+            }
+            critical_price.push_step(&mut exercise_boundary, step as f64 * dt);
+
+            if step <= 2 {
+                early_values[step as usize] = option_values[..=step as usize].to_vec();
             }
         }
 
-        option_values[0]
+        // Boundary was collected backwards in time
+        exercise_boundary.reverse();
+
+        TreeValues { early_values, exercise_boundary, u, d, dt }
     }
 
-    fn delta(&self, option_type: OptionType) -> f64 {
-        let epsilon = 0.01;
-        let _price = self.price(option_type.clone());
+    // Critical asset price at each time step below (put) or above (call) which early exercise
+    // is optimal, as (time, price) pairs. Empty for European options.
+    pub fn exercise_boundary(&self, option_type: OptionType) -> Vec<(f64, f64)> {
+        let black_scholes_step = matches!(
+            self.acceleration,
+            ConvergenceAcceleration::BlackScholes | ConvergenceAcceleration::BlackScholesRichardson
+        );
+        self.build_tree(self.steps, black_scholes_step, option_type).exercise_boundary
+    }
 
-        let mut model_up = self.clone();
-        model_up.underlying += epsilon;
-        let price_up = model_up.price(option_type.clone());
+    // Apply the configured convergence acceleration to a quantity read off a tree
+    fn accelerated<F>(&self, option_type: OptionType, value: F) -> f64
+    where
+        F: Fn(&TreeValues) -> f64,
+    {
+        let half_steps = self.steps / 2;
+        match self.acceleration {
+            ConvergenceAcceleration::None => value(&self.build_tree(self.steps, false, option_type)),
+            ConvergenceAcceleration::Richardson =>
+                2.0 * value(&self.build_tree(self.steps, false, option_type))
+                    - value(&self.build_tree(half_steps, false, option_type)),
+            ConvergenceAcceleration::BlackScholes => value(&self.build_tree(self.steps, true, option_type)),
+            ConvergenceAcceleration::BlackScholesRichardson =>
+                2.0 * value(&self.build_tree(self.steps, true, option_type))
+                    - value(&self.build_tree(half_steps, true, option_type)),
+        }
+    }
+}
 
-        let mut model_down = self.clone();
-        model_down.underlying -= epsilon;
-        let price_down = model_down.price(option_type.clone());
+impl OptionPricingModel for BinomialModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        self.accelerated(option_type, |tree| tree.early_values[0][0])
+    }
 
-        (price_up - price_down) / (2.0 * epsilon)
+    // Delta from the two nodes at step 1: (V_u - V_d) / (Su - Sd)
+    fn delta(&self, option_type: OptionType) -> f64 {
+        self.accelerated(option_type, |tree| {
+            let values = &tree.early_values[1];
+            (values[1] - values[0]) / (self.underlying * (tree.u - tree.d))
+        })
     }
 
+    // Gamma from the change in delta across the three nodes at step 2
     fn gamma(&self, option_type: OptionType) -> f64 {
-        let epsilon = 0.01;
-        let price = self.price(option_type.clone());
-
-        let mut model_up = self.clone();
-        model_up.underlying += epsilon;
-        let price_up = model_up.price(option_type.clone());
-
-        let mut model_down = self.clone();
-        model_down.underlying -= epsilon;
-        let price_down = model_down.price(option_type.clone());
-
-        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+        self.accelerated(option_type, |tree| {
+            let values = &tree.early_values[2];
+            let (s_uu, s_ud, s_dd) = (
+                self.underlying * tree.u * tree.u,
+                self.underlying * tree.u * tree.d,
+                self.underlying * tree.d * tree.d,
+            );
+            let delta_up = (values[2] - values[1]) / (s_uu - s_ud);
+            let delta_down = (values[1] - values[0]) / (s_ud - s_dd);
+            (delta_up - delta_down) / (0.5 * (s_uu - s_dd))
+        })
     }
 
+    // Theta from the middle node at step 2. It sits at the current price only when ud = 1, so for
+    // the other trees its value is moved back to S with the step-2 delta and gamma.
     fn theta(&self, option_type: OptionType) -> f64 {
-        let epsilon = 0.01;
-        let price = self.price(option_type.clone());
-
-        let mut model_up = self.clone();
-        model_up.maturity -= epsilon;
-        let price_up = model_up.price(option_type.clone());
+        self.accelerated(option_type, |tree| {
+            let values = &tree.early_values[2];
+            let (s_uu, s_ud, s_dd) = (
+                self.underlying * tree.u * tree.u,
+                self.underlying * tree.u * tree.d,
+                self.underlying * tree.d * tree.d,
+            );
+            let delta = (values[2] - values[0]) / (s_uu - s_dd);
+            let gamma = ((values[2] - values[1]) / (s_uu - s_ud) - (values[1] - values[0]) / (s_ud - s_dd))
+                / (0.5 * (s_uu - s_dd));
+            let shift = self.underlying - s_ud;
 
-        (price - price_up) / epsilon
+            let middle_value = values[1] + delta * shift + 0.5 * gamma * shift * shift;
+            (middle_value - tree.early_values[0][0]) / (2.0 * tree.dt)
+        })
     }
 
     fn vega(&self, option_type: OptionType) -> f64 {
@@ -111,3 +316,151 @@ impl OptionPricingModel for BinomialModel {
         (price_up - price) / epsilon
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TrinomialModel, TrinomialTree, FiniteDifferenceModel, FiniteDifferenceScheme, EarlyExerciseMethod};
+
+    const TREES: [BinomialTree; 5] = [
+        BinomialTree::CoxRossRubinstein,
+        BinomialTree::JarrowRudd,
+        BinomialTree::Tian,
+        BinomialTree::LeisenReimer,
+        BinomialTree::DriftAdjusted,
+    ];
+
+    fn black_scholes_model() -> BlackScholesModel {
+        BlackScholesModel {
+            underlying: 120.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+        }
+    }
+
+    fn binomial_model(tree: BinomialTree, steps: u32) -> BinomialModel {
+        BinomialModel {
+            underlying: 120.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            steps,
+            tree,
+            exercise: ExerciseStyle::European,
+            acceleration: ConvergenceAcceleration::None,
+            payoff: Payoff::Vanilla,
+        }
+    }
+
+    #[test]
+    fn tree_prices_match_black_scholes() {
+        for tree in TREES {
+            // Leisen-Reimer converges at O(1/N²), so far fewer steps are needed
+            let (steps, tolerance) = if tree == BinomialTree::LeisenReimer { (51, 1e-3) } else { (501, 1e-2) };
+            let model = binomial_model(tree, steps);
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (price, expected) = (model.price(option_type), black_scholes_model().price(option_type));
+                assert!((price - expected).abs() < tolerance, "{:?} {:?}: {} vs {}", tree, option_type, price, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn tree_theta_matches_black_scholes() {
+        for tree in TREES {
+            let model = binomial_model(tree, 501);
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (theta, expected) = (model.theta(option_type), black_scholes_model().theta(option_type));
+                assert!((theta - expected).abs() < 0.01, "{:?} {:?}: {} vs {}", tree, option_type, theta, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn accelerated_american_puts_match_a_fine_tree() {
+        let american_put = |steps: u32, acceleration: ConvergenceAcceleration| BinomialModel {
+            strike: 125.0,
+            exercise: ExerciseStyle::American,
+            acceleration,
+            ..binomial_model(BinomialTree::CoxRossRubinstein, steps)
+        }.price(OptionType::Put);
+        let reference = american_put(4001, ConvergenceAcceleration::None);
+        let error = |acceleration| (american_put(101, acceleration) - reference).abs();
+
+        assert!(error(ConvergenceAcceleration::BlackScholes) < 0.01);
+        assert!(error(ConvergenceAcceleration::BlackScholesRichardson) < 0.005);
+        assert!(error(ConvergenceAcceleration::BlackScholesRichardson) < error(ConvergenceAcceleration::None) / 4.0);
+    }
+
+    #[test]
+    fn richardson_extrapolation_matches_black_scholes() {
+        // Centred on the strike with an even number of steps, both trees put a node on the strike
+        // and converge smoothly enough to extrapolate
+        let model = BinomialModel {
+            acceleration: ConvergenceAcceleration::Richardson,
+            ..binomial_model(BinomialTree::DriftAdjusted, 100)
+        };
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (model.price(option_type), black_scholes_model().price(option_type));
+            assert!((price - expected).abs() < 1e-3, "{:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+
+    #[test]
+    fn american_put_boundaries_agree_across_models() {
+        let binomial = BinomialModel {
+            underlying: 100.0,
+            exercise: ExerciseStyle::American,
+            ..binomial_model(BinomialTree::CoxRossRubinstein, 500)
+        };
+        let trinomial = TrinomialModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            steps: 300,
+            stretch: f64::sqrt(1.5),
+            tree: TrinomialTree::KamradRitchken,
+            exercise: ExerciseStyle::American,
+            payoff: Payoff::Vanilla,
+        };
+        let finite_difference = FiniteDifferenceModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            price_steps: 400,
+            time_steps: 500,
+            scheme: FiniteDifferenceScheme::CrankNicolson { rannacher_steps: 2 },
+            exercise: ExerciseStyle::American,
+            early_exercise: EarlyExerciseMethod::Penalty,
+            payoff: Payoff::Vanilla,
+        };
+
+        // Critical price at the recorded time nearest to `time`
+        let critical_price = |boundary: &[(f64, f64)], time: f64| {
+            boundary.iter().min_by(|a, b| (a.0 - time).abs().total_cmp(&(b.0 - time).abs())).unwrap().1
+        };
+        let boundaries = [
+            binomial.exercise_boundary(OptionType::Put),
+            trinomial.exercise_boundary(OptionType::Put),
+            finite_difference.exercise_boundary(OptionType::Put),
+        ];
+        for time in [0.25, 0.5, 0.9] {
+            let prices: Vec<f64> = boundaries.iter().map(|boundary| critical_price(boundary, time)).collect();
+            for price in &prices {
+                assert!((price - prices[0]).abs() < 1.0, "t={}: {:?}", time, prices);
+                assert!(*price < 100.0);
+            }
+        }
+
+        // Calls on a non-dividend-paying asset are never exercised early
+        assert!(binomial.exercise_boundary(OptionType::Call).is_empty());
+        assert!(finite_difference.exercise_boundary(OptionType::Call).is_empty());
+    }
+}
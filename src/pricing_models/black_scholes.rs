@@ -1,5 +1,6 @@
-use crate::{OptionPricingModel,OptionType};
+use crate::{OptionPricingModel,OptionType,CharacteristicFunction};
 use distrs::Normal;
+use num_complex::Complex64;
 use core::f64::consts::E;
 
 #[derive(Clone)]
@@ -47,6 +48,15 @@ impl BlackScholesModel {
     }
 }
 
+// Characteristic function of ln(S_T) under GBM: exp(iu(ln S + (r - σ²/2)T) - σ²u²T/2)
+impl CharacteristicFunction for BlackScholesModel {
+    fn characteristic_function(&self, u: Complex64) -> Complex64 {
+        let i = Complex64::i();
+        let drift = self.underlying.ln() + (self.risk_free_rate - 0.5 * self.volatility.powi(2)) * self.maturity;
+        (i * u * drift - 0.5 * self.volatility.powi(2) * self.maturity * u * u).exp()
+    }
+}
+
 // Implement the OptionPricingModel trait for BlackScholesModel
 impl OptionPricingModel for BlackScholesModel {
     fn price(&self, option_type: OptionType) -> f64 {
@@ -0,0 +1,159 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+use crate::distributions::noncentral_chi_squared_cdf;
+
+// Constant elasticity of variance: dS = rS dt + σ S^α dW
+#[derive(Clone)]
+pub struct CevModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64, // σ: Scale of the local volatility σ S^(α-1)
+    pub risk_free_rate: f64,
+    pub elasticity: f64, // α: Elasticity of the diffusion, α < 1 gives the leverage-effect skew
+}
+
+impl CevModel {
+    // Schroder (1989) price in terms of the noncentral chi-squared CDF χ²(z; k, v)
+    fn schroder_price(&self, option_type: OptionType) -> f64 {
+        let (s, k, t, r) = (self.underlying, self.strike, self.maturity, self.risk_free_rate);
+        let one_minus_alpha = 1.0 - self.elasticity;
+        let discount = f64::exp(-r * t);
+
+        // At α = 1 the process is GBM and the chi-squared degrees of freedom 1/(1-α) blow up
+        if one_minus_alpha.abs() < 1e-6 {
+            return BlackScholesModel {
+                underlying: s,
+                strike: k,
+                maturity: t,
+                volatility: self.volatility,
+                risk_free_rate: r,
+            }.price(option_type);
+        }
+
+        // v = σ² (e^(2r(α-1)T) - 1) / (2r(α-1)), which tends to σ²T as r → 0
+        let v = if (r * one_minus_alpha).abs() < 1e-12 {
+            self.volatility.powi(2) * t
+        } else {
+            self.volatility.powi(2) * (f64::exp(-2.0 * r * one_minus_alpha * t) - 1.0) / (-2.0 * r * one_minus_alpha)
+        };
+
+        let a = (k * discount).powf(2.0 * one_minus_alpha) / (one_minus_alpha.powi(2) * v);
+        let b = 1.0 / one_minus_alpha;
+        let c = s.powf(2.0 * one_minus_alpha) / (one_minus_alpha.powi(2) * v);
+
+        // Probabilities multiplying S and K e^(-rT) in the call price
+        let (share_probability, strike_probability) = if self.elasticity < 1.0 {
+            (
+                1.0 - noncentral_chi_squared_cdf(a, b + 2.0, c),
+                noncentral_chi_squared_cdf(c, b, a),
+            )
+        } else {
+            (
+                1.0 - noncentral_chi_squared_cdf(c, -b, a),
+                noncentral_chi_squared_cdf(a, 2.0 - b, c),
+            )
+        };
+
+        match option_type {
+            OptionType::Call => s * share_probability - k * discount * strike_probability,
+            OptionType::Put => k * discount * (1.0 - strike_probability) - s * (1.0 - share_probability),
+        }
+    }
+}
+
+impl OptionPricingModel for CevModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        self.schroder_price(option_type)
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        // Sensitivity to the CEV volatility scale σ
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_elasticity_matches_black_scholes() {
+        let cev = CevModel {
+            underlying: 120.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            elasticity: 1.0,
+        };
+        let black_scholes = BlackScholesModel {
+            underlying: 120.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+        };
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (cev.price(option_type), black_scholes.price(option_type));
+            assert!((price - expected).abs() < 1e-12, "{:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+}
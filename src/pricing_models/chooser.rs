@@ -0,0 +1,175 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+use crate::distributions::{normal_cdf, bivariate_normal_cdf};
+
+// Terms of the call and put the holder chooses between
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ChooserStyle {
+    // Call or put with the model's strike and maturity (Rubinstein 1991)
+    Simple,
+    // Call with the model's strike and maturity, or a put with its own (Rubinstein 1991)
+    Complex { put_strike: f64, put_maturity: f64 },
+}
+
+// Chooser option: at `choice_time` the holder decides whether it is a call or a put. The holder
+// picks the type, so the `option_type` passed to the trait methods is ignored.
+#[derive(Clone)]
+pub struct ChooserOptionModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+    pub choice_time: f64,
+    pub style: ChooserStyle,
+}
+
+impl ChooserOptionModel {
+    const TOLERANCE: f64 = 1e-10;
+    const MAX_ITERATIONS: usize = 100;
+
+    // Option alive at the choice time with the asset at `asset_price`, as Black-Scholes on the
+    // dividend-adjusted spot
+    fn model_at_choice(&self, asset_price: f64, strike: f64, maturity: f64) -> BlackScholesModel {
+        let remaining = maturity - self.choice_time;
+        BlackScholesModel {
+            underlying: asset_price * f64::exp(-self.dividend_yield * remaining),
+            strike,
+            maturity: remaining,
+            volatility: self.volatility,
+            risk_free_rate: self.risk_free_rate,
+        }
+    }
+
+    // Asset price at the choice time where the call and the put are worth the same, by Newton's
+    // method on the call minus the put, which increases in the asset price
+    fn critical_price(&self, put_strike: f64, put_maturity: f64) -> f64 {
+        let mut asset_price = self.strike;
+
+        for _ in 0..Self::MAX_ITERATIONS {
+            let call = self.model_at_choice(asset_price, self.strike, self.maturity);
+            let put = self.model_at_choice(asset_price, put_strike, put_maturity);
+            let difference = call.price(OptionType::Call) - put.price(OptionType::Put);
+            if difference.abs() < Self::TOLERANCE {
+                break;
+            }
+
+            let slope = f64::exp(-self.dividend_yield * (self.maturity - self.choice_time)) * call.delta(OptionType::Call)
+                - f64::exp(-self.dividend_yield * (put_maturity - self.choice_time)) * put.delta(OptionType::Put);
+            asset_price = f64::max(asset_price - difference / slope, 0.5 * asset_price);
+        }
+
+        asset_price
+    }
+
+    fn simple_price(&self) -> f64 {
+        let (s, k, t, t1, r, sigma) =
+            (self.underlying, self.strike, self.maturity, self.choice_time, self.risk_free_rate, self.volatility);
+        let b = r - self.dividend_yield;
+
+        let d = (f64::ln(s / k) + (b + 0.5 * sigma.powi(2)) * t) / (sigma * f64::sqrt(t));
+        let y = (f64::ln(s / k) + b * t + 0.5 * sigma.powi(2) * t1) / (sigma * f64::sqrt(t1));
+        let asset = s * f64::exp(-self.dividend_yield * t);
+        let discounted_strike = k * f64::exp(-r * t);
+
+        // A call until maturity plus a put that is held when the call is out of the money at t1
+        asset * normal_cdf(d) - discounted_strike * normal_cdf(d - sigma * f64::sqrt(t))
+            - asset * normal_cdf(-y) + discounted_strike * normal_cdf(-y + sigma * f64::sqrt(t1))
+    }
+
+    fn complex_price(&self, put_strike: f64, put_maturity: f64) -> f64 {
+        let (s, t, r, sigma) = (self.underlying, self.choice_time, self.risk_free_rate, self.volatility);
+        let (call_strike, call_maturity) = (self.strike, self.maturity);
+        let b = r - self.dividend_yield;
+
+        let critical = self.critical_price(put_strike, put_maturity);
+        let d1 = (f64::ln(s / critical) + (b + 0.5 * sigma.powi(2)) * t) / (sigma * f64::sqrt(t));
+        let d2 = d1 - sigma * f64::sqrt(t);
+        let y1 = (f64::ln(s / call_strike) + (b + 0.5 * sigma.powi(2)) * call_maturity) / (sigma * f64::sqrt(call_maturity));
+        let y2 = (f64::ln(s / put_strike) + (b + 0.5 * sigma.powi(2)) * put_maturity) / (sigma * f64::sqrt(put_maturity));
+        let rho1 = f64::sqrt(t / call_maturity);
+        let rho2 = f64::sqrt(t / put_maturity);
+
+        s * f64::exp(-self.dividend_yield * call_maturity) * bivariate_normal_cdf(d1, y1, rho1)
+            - call_strike * f64::exp(-r * call_maturity)
+                * bivariate_normal_cdf(d2, y1 - sigma * f64::sqrt(call_maturity), rho1)
+            - s * f64::exp(-self.dividend_yield * put_maturity) * bivariate_normal_cdf(-d1, -y2, rho2)
+            + put_strike * f64::exp(-r * put_maturity)
+                * bivariate_normal_cdf(-d2, -y2 + sigma * f64::sqrt(put_maturity), rho2)
+    }
+}
+
+impl OptionPricingModel for ChooserOptionModel {
+    fn price(&self, _option_type: OptionType) -> f64 {
+        match self.style {
+            ChooserStyle::Simple => self.simple_price(),
+            ChooserStyle::Complex { put_strike, put_maturity } => self.complex_price(put_strike, put_maturity),
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    // All dates move together
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        model_down.choice_time -= epsilon;
+        if let ChooserStyle::Complex { put_strike, put_maturity } = self.style {
+            model_down.style = ChooserStyle::Complex { put_strike, put_maturity: put_maturity - epsilon };
+        }
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
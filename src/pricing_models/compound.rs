@@ -0,0 +1,224 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+use crate::distributions::{normal_cdf, bivariate_normal_cdf};
+
+// Option on an option, priced with Geske's (1979) formula. The `option_type` passed to the
+// trait methods is the type of the compound option; `underlying_option` is the type of the
+// option it delivers.
+#[derive(Clone)]
+pub struct CompoundOptionModel {
+    pub underlying: f64,
+    pub strike: f64,   // K1: Strike of the underlying option
+    pub maturity: f64, // T2: Maturity of the underlying option
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+    pub compound_strike: f64,   // K2: Premium paid for the underlying option at exercise
+    pub compound_maturity: f64, // t1: Exercise date of the compound option, before T2
+    pub underlying_option: OptionType,
+}
+
+impl CompoundOptionModel {
+    const TOLERANCE: f64 = 1e-10;
+    const MAX_ITERATIONS: usize = 100;
+
+    // Underlying option at time t1 with the asset at `asset_price`, as Black-Scholes on the
+    // dividend-adjusted spot
+    fn underlying_option_model(&self, asset_price: f64) -> BlackScholesModel {
+        let remaining = self.maturity - self.compound_maturity;
+        BlackScholesModel {
+            underlying: asset_price * f64::exp(-self.dividend_yield * remaining),
+            strike: self.strike,
+            maturity: remaining,
+            volatility: self.volatility,
+            risk_free_rate: self.risk_free_rate,
+        }
+    }
+
+    // Asset price I* at t1 where the underlying option is worth exactly K2, by Newton's method
+    fn critical_price(&self) -> f64 {
+        let remaining = self.maturity - self.compound_maturity;
+        let mut asset_price = self.strike;
+
+        for _ in 0..Self::MAX_ITERATIONS {
+            let model = self.underlying_option_model(asset_price);
+            let value = model.price(self.underlying_option) - self.compound_strike;
+            if value.abs() < Self::TOLERANCE {
+                break;
+            }
+
+            let delta = f64::exp(-self.dividend_yield * remaining) * model.delta(self.underlying_option);
+            asset_price = f64::max(asset_price - value / delta, 0.5 * asset_price);
+        }
+
+        asset_price
+    }
+}
+
+impl OptionPricingModel for CompoundOptionModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let (s, r, sigma) = (self.underlying, self.risk_free_rate, self.volatility);
+        let (t1, t2) = (self.compound_maturity, self.maturity);
+        let b = r - self.dividend_yield;
+
+        let critical = self.critical_price();
+        let y1 = (f64::ln(s / critical) + (b + 0.5 * sigma.powi(2)) * t1) / (sigma * f64::sqrt(t1));
+        let y2 = y1 - sigma * f64::sqrt(t1);
+        let z1 = (f64::ln(s / self.strike) + (b + 0.5 * sigma.powi(2)) * t2) / (sigma * f64::sqrt(t2));
+        let z2 = z1 - sigma * f64::sqrt(t2);
+        let rho = f64::sqrt(t1 / t2);
+
+        let asset = s * f64::exp(-self.dividend_yield * t2);
+        let underlying_strike = self.strike * f64::exp(-r * t2);
+        let compound_strike = self.compound_strike * f64::exp(-r * t1);
+
+        match (option_type, self.underlying_option) {
+            (OptionType::Call, OptionType::Call) =>
+                asset * bivariate_normal_cdf(z1, y1, rho) - underlying_strike * bivariate_normal_cdf(z2, y2, rho)
+                    - compound_strike * normal_cdf(y2),
+            (OptionType::Put, OptionType::Call) =>
+                underlying_strike * bivariate_normal_cdf(z2, -y2, -rho) - asset * bivariate_normal_cdf(z1, -y1, -rho)
+                    + compound_strike * normal_cdf(-y2),
+            (OptionType::Call, OptionType::Put) =>
+                underlying_strike * bivariate_normal_cdf(-z2, -y2, rho) - asset * bivariate_normal_cdf(-z1, -y1, rho)
+                    - compound_strike * normal_cdf(-y2),
+            (OptionType::Put, OptionType::Put) =>
+                asset * bivariate_normal_cdf(-z1, y1, -rho) - underlying_strike * bivariate_normal_cdf(-z2, y2, -rho)
+                    + compound_strike * normal_cdf(y2),
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    // Both maturities move together
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        model_down.compound_maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rand_distr::{Normal, Distribution};
+
+    fn compound_model(underlying_option: OptionType) -> CompoundOptionModel {
+        CompoundOptionModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.02,
+            compound_strike: 6.0,
+            compound_maturity: 0.5,
+            underlying_option,
+        }
+    }
+
+    // Sample the asset at t1 exactly and value the delivered option there with Black-Scholes
+    fn nested_monte_carlo_price(model: &CompoundOptionModel, option_type: OptionType, simulations: u32) -> f64 {
+        let mut rng = StdRng::seed_from_u64(42);
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+        let t1 = model.compound_maturity;
+        let drift = (model.risk_free_rate - model.dividend_yield - 0.5 * model.volatility.powi(2)) * t1;
+
+        let total: f64 = (0..simulations)
+            .map(|_| {
+                let asset_price = model.underlying * f64::exp(drift + model.volatility * f64::sqrt(t1) * normal_dist.sample(&mut rng));
+                let value = model.underlying_option_model(asset_price).price(model.underlying_option);
+                match option_type {
+                    OptionType::Call => f64::max(value - model.compound_strike, 0.0),
+                    OptionType::Put => f64::max(model.compound_strike - value, 0.0),
+                }
+            })
+            .sum();
+
+        total / simulations as f64 * f64::exp(-model.risk_free_rate * t1)
+    }
+
+    #[test]
+    fn geske_matches_nested_black_scholes_monte_carlo() {
+        for underlying_option in [OptionType::Call, OptionType::Put] {
+            let model = compound_model(underlying_option);
+            // About three standard errors of the Monte Carlo price
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (price, expected) = (model.price(option_type), nested_monte_carlo_price(&model, option_type, 200_000));
+                assert!((price - expected).abs() < 0.05, "{:?} on {:?}: {} vs {}", option_type, underlying_option, price, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn compound_call_minus_put_is_a_forward_on_the_underlying_option() {
+        for underlying_option in [OptionType::Call, OptionType::Put] {
+            let model = compound_model(underlying_option);
+            let underlying = BlackScholesModel {
+                underlying: model.underlying * f64::exp(-model.dividend_yield * model.maturity),
+                strike: model.strike,
+                maturity: model.maturity,
+                volatility: model.volatility,
+                risk_free_rate: model.risk_free_rate,
+            };
+            let difference = model.price(OptionType::Call) - model.price(OptionType::Put);
+            let expected = underlying.price(underlying_option) - model.compound_strike * f64::exp(-model.risk_free_rate * model.compound_maturity);
+            assert!((difference - expected).abs() < 1e-8, "{:?}: {} vs {}", underlying_option, difference, expected);
+        }
+    }
+}
@@ -0,0 +1,437 @@
+use crate::{OptionPricingModel, OptionType, ExerciseStyle, Payoff};
+use crate::pricing_models::lattice::{payoff_value, payoff_strike, CriticalPrice};
+
+// Time-stepping scheme for the Black-Scholes PDE
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum FiniteDifferenceScheme {
+    // Forward Euler, stable only for Δτ ≤ ΔS² / (σ² S_max²)
+    Explicit,
+    // Backward Euler, unconditionally stable and first order in time
+    Implicit,
+    // Crank-Nicolson, second order in time. The first `rannacher_steps` steps are replaced by
+    // two implicit half steps each to damp the oscillations caused by the non-smooth payoff.
+    CrankNicolson { rannacher_steps: usize },
+}
+
+// How the early exercise constraint V ≥ payoff is enforced for implicit schemes
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum EarlyExerciseMethod {
+    // Projected successive over-relaxation
+    Psor { relaxation: f64, tolerance: f64 },
+    // Forsyth-Vetzal penalty iteration
+    Penalty,
+}
+
+#[derive(Clone)]
+pub struct FiniteDifferenceModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub price_steps: usize, // M: Number of intervals in the underlying price grid
+    pub time_steps: usize,  // N: Number of time steps
+    pub scheme: FiniteDifferenceScheme,
+    pub exercise: ExerciseStyle,
+    pub early_exercise: EarlyExerciseMethod,
+    pub payoff: Payoff,
+}
+
+// Solution of the PDE on the price grid at t = 0 and one time step later
+struct GridSolution {
+    values: Vec<f64>,
+    next_values: Vec<f64>,
+    ds: f64,
+    dt: f64,
+    node: usize, // Index of the current underlying price on the grid
+    exercise_boundary: Vec<(f64, f64)>, // (time, critical asset price) for steps with early exercise
+}
+
+impl FiniteDifferenceModel {
+    // Width of the grid above the larger of S and K, in standard deviations of ln S_T
+    const GRID_WIDTH: f64 = 5.0;
+    const PENALTY: f64 = 1e8;
+    const MAX_ITERATIONS: usize = 10_000;
+
+    // Dirichlet values at S = 0 and S = S_max with time τ remaining to maturity: the European
+    // asymptotes, floored at the payoff for American options
+    fn boundary_values(&self, s_max: f64, tau: f64, option_type: OptionType) -> (f64, f64) {
+        let discounted_strike = self.strike * f64::exp(-self.risk_free_rate * tau);
+        let (low, high) = match option_type {
+            // A deep in-the-money power call is worth e^(-rτ) (E[S_T^n] - K)
+            OptionType::Call => match self.payoff {
+                Payoff::Power { exponent: n } => {
+                    let growth = n * self.risk_free_rate + 0.5 * n * (n - 1.0) * self.volatility.powi(2);
+                    (0.0, (s_max.powf(n) * f64::exp(growth * tau) - self.strike) * f64::exp(-self.risk_free_rate * tau))
+                }
+                _ => (0.0, s_max - discounted_strike),
+            },
+            OptionType::Put => (discounted_strike, 0.0),
+        };
+
+        match self.exercise {
+            ExerciseStyle::European => (low, high),
+            ExerciseStyle::American => (
+                f64::max(low, payoff_value(self.payoff, self.strike, 0.0, option_type)),
+                f64::max(high, payoff_value(self.payoff, self.strike, s_max, option_type)),
+            ),
+        }
+    }
+
+    // Grid sizes, with at least two price intervals and one time step
+    fn steps(&self) -> (usize, usize) {
+        (self.price_steps.max(2), self.time_steps.max(1))
+    }
+
+    // Grid spacing chosen so that the current underlying price falls exactly on a node
+    fn grid(&self) -> (f64, usize) {
+        let (price_steps, _) = self.steps();
+        let s_max = f64::max(self.underlying, payoff_strike(self.payoff, self.strike))
+            * f64::exp(Self::GRID_WIDTH * self.volatility * f64::sqrt(self.maturity));
+        let node = ((price_steps as f64 * self.underlying / s_max).round() as usize).clamp(1, price_steps - 1);
+        (self.underlying / node as f64, node)
+    }
+
+    // Coefficients of V_{i-1}, V_i and V_{i+1} in Δτ L V at node i, where
+    // L V = σ²S²/2 V_SS + rS V_S - rV on the grid S_i = iΔS
+    fn operator(&self, i: usize, dt: f64) -> (f64, f64, f64) {
+        let i = i as f64;
+        let sigma2 = self.volatility.powi(2);
+        let r = self.risk_free_rate;
+        (
+            0.5 * dt * (sigma2 * i * i - r * i),
+            -dt * (sigma2 * i * i + r),
+            0.5 * dt * (sigma2 * i * i + r * i),
+        )
+    }
+
+    fn solve(&self, option_type: OptionType) -> GridSolution {
+        let (ds, node) = self.grid();
+        let (m, time_steps) = self.steps();
+        let s_max = m as f64 * ds;
+        let dt = self.maturity / time_steps as f64;
+
+        let payoff: Vec<f64> = (0..=m).map(|i| payoff_value(self.payoff, self.strike, i as f64 * ds, option_type)).collect();
+        let mut values = payoff.clone();
+        let mut next_values = values.clone();
+        let mut exercise_boundary = Vec::new();
+        let mut critical_price = CriticalPrice::new(self.payoff, self.strike, option_type, self.exercise);
+
+        for step in 0..time_steps {
+            let tau = (step + 1) as f64 * dt;
+            next_values.clone_from(&values);
+
+            match self.scheme {
+                FiniteDifferenceScheme::Explicit => {
+                    self.explicit_step(&mut values, dt, tau, s_max, &payoff, option_type)
+                }
+                FiniteDifferenceScheme::Implicit => {
+                    self.theta_step(&mut values, dt, 1.0, tau, s_max, &payoff, option_type)
+                }
+                FiniteDifferenceScheme::CrankNicolson { rannacher_steps } if step < rannacher_steps => {
+                    self.theta_step(&mut values, 0.5 * dt, 1.0, tau - 0.5 * dt, s_max, &payoff, option_type);
+                    self.theta_step(&mut values, 0.5 * dt, 1.0, tau, s_max, &payoff, option_type);
+                }
+                FiniteDifferenceScheme::CrankNicolson { .. } => {
+                    self.theta_step(&mut values, dt, 0.5, tau, s_max, &payoff, option_type)
+                }
+            }
+
+            for (i, value) in values.iter().enumerate().take(m).skip(1) {
+                critical_price.record(*value, i as f64 * ds);
+            }
+            critical_price.push_step(&mut exercise_boundary, self.maturity - tau);
+        }
+
+        // Boundary was collected backwards in time
+        exercise_boundary.reverse();
+
+        GridSolution { values, next_values, ds, dt, node, exercise_boundary }
+    }
+
+    // Critical asset price at each time step below (put) or above (call) which early exercise
+    // is optimal, as (time, price) pairs. Empty for European options.
+    pub fn exercise_boundary(&self, option_type: OptionType) -> Vec<(f64, f64)> {
+        self.solve(option_type).exercise_boundary
+    }
+
+    fn explicit_step(&self, values: &mut [f64], dt: f64, tau: f64, s_max: f64, payoff: &[f64], option_type: OptionType) {
+        let m = values.len() - 1;
+        let old = values.to_vec();
+
+        for i in 1..m {
+            let (a, b, c) = self.operator(i, dt);
+            values[i] = old[i] + a * old[i - 1] + b * old[i] + c * old[i + 1];
+            if self.exercise == ExerciseStyle::American {
+                values[i] = values[i].max(payoff[i]);
+            }
+        }
+
+        (values[0], values[m]) = self.boundary_values(s_max, tau, option_type);
+    }
+
+    // θ-scheme step: (I - θΔτL) V^(n+1) = (I + (1-θ)ΔτL) V^n, θ = 1 implicit, θ = 1/2 Crank-Nicolson
+    #[allow(clippy::too_many_arguments)]
+    fn theta_step(
+        &self,
+        values: &mut [f64],
+        dt: f64,
+        theta: f64,
+        tau: f64,
+        s_max: f64,
+        payoff: &[f64],
+        option_type: OptionType,
+    ) {
+        let m = values.len() - 1;
+        let (low, high) = self.boundary_values(s_max, tau, option_type);
+
+        // Tridiagonal system over the interior nodes 1..m-1
+        let mut lower = vec![0.0; m - 1];
+        let mut diagonal = vec![0.0; m - 1];
+        let mut upper = vec![0.0; m - 1];
+        let mut rhs = vec![0.0; m - 1];
+
+        for i in 1..m {
+            let (a, b, c) = self.operator(i, dt);
+            let k = i - 1;
+            lower[k] = -theta * a;
+            diagonal[k] = 1.0 - theta * b;
+            upper[k] = -theta * c;
+            rhs[k] = values[i] + (1.0 - theta) * (a * values[i - 1] + b * values[i] + c * values[i + 1]);
+        }
+
+        // Known boundary values move to the right-hand side
+        rhs[0] -= lower[0] * low;
+        rhs[m - 2] -= upper[m - 2] * high;
+
+        let interior = match self.exercise {
+            ExerciseStyle::European => solve_tridiagonal(&lower, &diagonal, &upper, &rhs),
+            ExerciseStyle::American => match self.early_exercise {
+                EarlyExerciseMethod::Psor { relaxation, tolerance } => {
+                    let start = values[1..m].to_vec();
+                    psor(&lower, &diagonal, &upper, &rhs, &payoff[1..m], start, relaxation, tolerance)
+                }
+                EarlyExerciseMethod::Penalty => self.penalty(&lower, &diagonal, &upper, &rhs, &payoff[1..m]),
+            },
+        };
+
+        values[0] = low;
+        values[1..m].copy_from_slice(&interior);
+        values[m] = high;
+    }
+
+    // Iterate (A + P) V = b + P g, where P penalises nodes below the payoff g, until the
+    // set of exercised nodes stops changing
+    fn penalty(&self, lower: &[f64], diagonal: &[f64], upper: &[f64], rhs: &[f64], payoff: &[f64]) -> Vec<f64> {
+        let mut solution = solve_tridiagonal(lower, diagonal, upper, rhs);
+
+        for _ in 0..Self::MAX_ITERATIONS {
+            let active: Vec<bool> = solution.iter().zip(payoff).map(|(v, g)| v < g).collect();
+
+            let penalised_diagonal: Vec<f64> = diagonal
+                .iter()
+                .zip(&active)
+                .map(|(d, &is_active)| if is_active { d + Self::PENALTY } else { *d })
+                .collect();
+            let penalised_rhs: Vec<f64> = rhs
+                .iter()
+                .zip(payoff)
+                .zip(&active)
+                .map(|((b, g), &is_active)| if is_active { b + Self::PENALTY * g } else { *b })
+                .collect();
+
+            solution = solve_tridiagonal(lower, &penalised_diagonal, upper, &penalised_rhs);
+
+            let next_active: Vec<bool> = solution.iter().zip(payoff).map(|(v, g)| v < g).collect();
+            if next_active == active {
+                break;
+            }
+        }
+
+        // Remove the O(1/penalty) violation left at exercised nodes
+        solution.iter().zip(payoff).map(|(v, g)| v.max(*g)).collect()
+    }
+}
+
+// Thomas algorithm for a tridiagonal system
+pub(crate) fn solve_tridiagonal(lower: &[f64], diagonal: &[f64], upper: &[f64], rhs: &[f64]) -> Vec<f64> {
+    let n = diagonal.len();
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+
+    c[0] = upper[0] / diagonal[0];
+    d[0] = rhs[0] / diagonal[0];
+    for i in 1..n {
+        let denominator = diagonal[i] - lower[i] * c[i - 1];
+        c[i] = upper[i] / denominator;
+        d[i] = (rhs[i] - lower[i] * d[i - 1]) / denominator;
+    }
+
+    let mut x = vec![0.0; n];
+    x[n - 1] = d[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = d[i] - c[i] * x[i + 1];
+    }
+
+    x
+}
+
+// Projected SOR for the linear complementarity problem A V ≥ b, V ≥ g
+#[allow(clippy::too_many_arguments)]
+fn psor(
+    lower: &[f64],
+    diagonal: &[f64],
+    upper: &[f64],
+    rhs: &[f64],
+    payoff: &[f64],
+    mut x: Vec<f64>,
+    relaxation: f64,
+    tolerance: f64,
+) -> Vec<f64> {
+    let n = x.len();
+
+    for _ in 0..FiniteDifferenceModel::MAX_ITERATIONS {
+        let mut error = 0.0;
+        for i in 0..n {
+            let below = if i > 0 { lower[i] * x[i - 1] } else { 0.0 };
+            let above = if i < n - 1 { upper[i] * x[i + 1] } else { 0.0 };
+            let gauss_seidel = (rhs[i] - below - above) / diagonal[i];
+            let updated = f64::max(payoff[i], x[i] + relaxation * (gauss_seidel - x[i]));
+            error += (updated - x[i]).powi(2);
+            x[i] = updated;
+        }
+        if error.sqrt() < tolerance {
+            break;
+        }
+    }
+
+    x
+}
+
+impl OptionPricingModel for FiniteDifferenceModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let solution = self.solve(option_type);
+        solution.values[solution.node]
+    }
+
+    // Central difference on the grid
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let solution = self.solve(option_type);
+        let i = solution.node;
+        (solution.values[i + 1] - solution.values[i - 1]) / (2.0 * solution.ds)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let solution = self.solve(option_type);
+        let i = solution.node;
+        (solution.values[i + 1] - 2.0 * solution.values[i] + solution.values[i - 1]) / solution.ds.powi(2)
+    }
+
+    // Difference between the last two time levels of the grid
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let solution = self.solve(option_type);
+        let i = solution.node;
+        (solution.next_values[i] - solution.values[i]) / solution.dt
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PowerOptionModel, GapOptionModel};
+
+    fn finite_difference_model(strike: f64, payoff: Payoff) -> FiniteDifferenceModel {
+        FiniteDifferenceModel {
+            underlying: 100.0,
+            strike,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            price_steps: 400,
+            time_steps: 400,
+            scheme: FiniteDifferenceScheme::CrankNicolson { rannacher_steps: 2 },
+            exercise: ExerciseStyle::European,
+            early_exercise: EarlyExerciseMethod::Penalty,
+            payoff,
+        }
+    }
+
+    #[test]
+    fn power_and_gap_payoffs_match_closed_forms() {
+        let power = PowerOptionModel {
+            underlying: 100.0,
+            strike: 10_000.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+            exponent: 2.0,
+        };
+        let gap = GapOptionModel {
+            underlying: 100.0,
+            strike: 100.0,
+            trigger_strike: 105.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+        };
+        let power_grid = finite_difference_model(10_000.0, Payoff::Power { exponent: 2.0 });
+        let gap_grid = finite_difference_model(100.0, Payoff::Gap { trigger_strike: 105.0 });
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (power_grid.price(option_type), power.price(option_type));
+            assert!((price - expected).abs() < 0.001 * expected.max(100.0), "power {:?}: {} vs {}", option_type, price, expected);
+
+            let (price, expected) = (gap_grid.price(option_type), gap.price(option_type));
+            assert!((price - expected).abs() < 0.05, "gap {:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+
+    #[test]
+    fn tiny_grids_do_not_panic() {
+        let schemes = [
+            FiniteDifferenceScheme::Explicit,
+            FiniteDifferenceScheme::Implicit,
+            FiniteDifferenceScheme::CrankNicolson { rannacher_steps: 2 },
+        ];
+        for scheme in schemes {
+            for (price_steps, time_steps) in [(0, 0), (1, 1), (2, 1)] {
+                let model = FiniteDifferenceModel {
+                    price_steps,
+                    time_steps,
+                    scheme,
+                    exercise: ExerciseStyle::American,
+                    ..finite_difference_model(100.0, Payoff::Vanilla)
+                };
+                for option_type in [OptionType::Call, OptionType::Put] {
+                    assert!(model.price(option_type).is_finite());
+                    assert!(model.delta(option_type).is_finite());
+                    assert!(model.gamma(option_type).is_finite());
+                    assert!(model.theta(option_type).is_finite());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+
+// Forward-start option (Rubinstein 1990): at `start_time` an option maturing at `maturity` is
+// struck at `strike_ratio` times the spot price on that date
+#[derive(Clone)]
+pub struct ForwardStartModel {
+    pub underlying: f64,
+    pub strike_ratio: f64, // α: Strike as a fraction of the spot at the start date, 1.0 for at-the-money
+    pub start_time: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+}
+
+impl ForwardStartModel {
+    // By homogeneity, the option at the start date is worth S(t1) times an option on a unit spot
+    // with strike α, so today it is S e^(-q t1) times that value
+    fn unit_option(&self) -> BlackScholesModel {
+        let remaining = self.maturity - self.start_time;
+        BlackScholesModel {
+            underlying: f64::exp(-self.dividend_yield * remaining),
+            strike: self.strike_ratio,
+            maturity: remaining,
+            volatility: self.volatility,
+            risk_free_rate: self.risk_free_rate,
+        }
+    }
+}
+
+impl OptionPricingModel for ForwardStartModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        self.underlying * f64::exp(-self.dividend_yield * self.start_time) * self.unit_option().price(option_type)
+    }
+
+    // The price is linear in the spot
+    fn delta(&self, option_type: OptionType) -> f64 {
+        self.price(option_type) / self.underlying
+    }
+
+    fn gamma(&self, _option_type: OptionType) -> f64 {
+        0.0
+    }
+
+    // Both dates move together
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        model_down.start_time = f64::max(self.start_time - epsilon, 0.0);
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn immediate_start_matches_black_scholes() {
+        for (strike_ratio, dividend_yield) in [(1.0, 0.0), (0.9, 0.03), (1.1, 0.03)] {
+            let model = ForwardStartModel {
+                underlying: 80.0,
+                strike_ratio,
+                start_time: 0.0,
+                maturity: 1.5,
+                volatility: 0.3,
+                risk_free_rate: 0.05,
+                dividend_yield,
+            };
+            // Dividend-adjusted spot with the strike fixed today at α S
+            let black_scholes = BlackScholesModel {
+                underlying: 80.0 * f64::exp(-dividend_yield * 1.5),
+                strike: strike_ratio * 80.0,
+                maturity: 1.5,
+                volatility: 0.3,
+                risk_free_rate: 0.05,
+            };
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (price, expected) = (model.price(option_type), black_scholes.price(option_type));
+                assert!((price - expected).abs() < 1e-10, "α = {} {:?}: {} vs {}", strike_ratio, option_type, price, expected);
+            }
+        }
+    }
+}
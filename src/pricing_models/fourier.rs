@@ -0,0 +1,373 @@
+use crate::{CharacteristicFunction, OptionType};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+use std::fmt;
+
+// Upper limit and number of Simpson panels for the Fourier inversion integrals
+const INTEGRATION_LIMIT: f64 = 200.0;
+const INTEGRATION_PANELS: usize = 4000;
+
+// Composite Simpson's rule over (0, INTEGRATION_LIMIT]
+fn integrate<F>(integrand: F) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let n = INTEGRATION_PANELS;
+    let lower = 1e-8; // The integrands have finite limits at zero but cannot be evaluated there
+    let h = (INTEGRATION_LIMIT - lower) / n as f64;
+
+    let mut total = integrand(lower) + integrand(INTEGRATION_LIMIT);
+    for k in 1..n {
+        let weight = if k % 2 == 1 { 4.0 } else { 2.0 };
+        total += weight * integrand(lower + k as f64 * h);
+    }
+
+    total * h / 3.0
+}
+
+// Risk-neutral exercise probabilities (P1, P2) via Gil-Pelaez inversion.
+// P1 is taken under the share measure, obtained by dividing φ(u - i) by φ(-i) = E[S_T].
+pub(crate) fn exercise_probabilities(model: &impl CharacteristicFunction, strike: f64) -> (f64, f64) {
+    let i = Complex64::i();
+    let log_strike = strike.ln();
+    let forward = model.characteristic_function(-i);
+
+    let p1 = 0.5 + integrate(|u| {
+        let phi = model.characteristic_function(Complex64::new(u, -1.0));
+        ((-i * u * log_strike).exp() * phi / (i * u * forward)).re
+    }) / PI;
+
+    let p2 = 0.5 + integrate(|u| {
+        let phi = model.characteristic_function(Complex64::new(u, 0.0));
+        ((-i * u * log_strike).exp() * phi / (i * u)).re
+    }) / PI;
+
+    (p1, p2)
+}
+
+// European price: e^(-rT) (F P1 - K P2) for a call, and put-call parity for a put
+pub(crate) fn gil_pelaez_price(
+    model: &impl CharacteristicFunction,
+    strike: f64,
+    maturity: f64,
+    risk_free_rate: f64,
+    option_type: OptionType,
+) -> f64 {
+    let (p1, p2) = exercise_probabilities(model, strike);
+    let forward = model.characteristic_function(-Complex64::i()).re;
+    let discount = f64::exp(-risk_free_rate * maturity);
+
+    let call_price = discount * (forward * p1 - strike * p2);
+    match option_type {
+        OptionType::Call => call_price,
+        OptionType::Put => call_price - discount * (forward - strike),
+    }
+}
+
+// Numerical scheme used to invert the characteristic function over a strip of strikes
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum FourierMethod {
+    // Carr-Madan (1999): FFT of the damped call price on a log-strike grid
+    CarrMadan {
+        points: usize, // N: FFT size, a power of two
+        spacing: f64,  // η: Grid spacing in the Fourier domain
+        damping: f64,  // α: Damping exponent applied to the call price
+    },
+    // Fang-Oosterlee (2008): Fourier-cosine expansion of the density
+    Cos {
+        terms: usize,    // N: Number of cosine terms
+        truncation: f64, // L: Width of the integration range in standard deviations
+    },
+}
+
+impl FourierMethod {
+    pub const CARR_MADAN: FourierMethod = FourierMethod::CarrMadan { points: 4096, spacing: 0.25, damping: 1.5 };
+    pub const COS: FourierMethod = FourierMethod::Cos { terms: 256, truncation: 12.0 };
+}
+
+// Settings rejected by `FourierEngine::price_strikes`
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum FourierError {
+    // The Carr-Madan FFT size must be a power of two of at least 2
+    InvalidPoints(usize),
+    // Carr-Madan prices are interpolated on the FFT's log-strike grid, so strikes must lie on it
+    StrikeOutsideGrid { strike: f64, lower: f64, upper: f64 },
+}
+
+impl fmt::Display for FourierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FourierError::InvalidPoints(points) =>
+                write!(f, "Carr-Madan needs a power-of-two number of points of at least 2, got {}", points),
+            FourierError::StrikeOutsideGrid { strike, lower, upper } =>
+                write!(f, "strike {} is outside the Carr-Madan grid [{}, {}]", strike, lower, upper),
+        }
+    }
+}
+
+impl std::error::Error for FourierError {}
+
+// Prices a strip of European strikes at once from any model's characteristic function
+#[derive(Clone)]
+pub struct FourierEngine {
+    pub maturity: f64,
+    pub risk_free_rate: f64,
+    pub method: FourierMethod,
+}
+
+impl FourierEngine {
+    pub fn price_strikes(
+        &self,
+        model: &impl CharacteristicFunction,
+        strikes: &[f64],
+        option_type: OptionType,
+    ) -> Result<Vec<f64>, FourierError> {
+        let call_prices = match self.method {
+            FourierMethod::CarrMadan { points, spacing, damping } => {
+                if points < 2 || !points.is_power_of_two() {
+                    return Err(FourierError::InvalidPoints(points));
+                }
+                self.carr_madan_call_prices(model, strikes, points, spacing, damping)?
+            }
+            FourierMethod::Cos { terms, truncation } =>
+                self.cos_call_prices(model, strikes, terms, truncation),
+        };
+
+        Ok(match option_type {
+            OptionType::Call => call_prices,
+            OptionType::Put => {
+                // Put-call parity: P = C - e^(-rT) (F - K)
+                let forward = model.characteristic_function(-Complex64::i()).re;
+                let discount = f64::exp(-self.risk_free_rate * self.maturity);
+                call_prices
+                    .iter()
+                    .zip(strikes)
+                    .map(|(call, strike)| call - discount * (forward - strike))
+                    .collect()
+            }
+        })
+    }
+
+    fn carr_madan_call_prices(
+        &self,
+        model: &impl CharacteristicFunction,
+        strikes: &[f64],
+        points: usize,
+        spacing: f64,
+        damping: f64,
+    ) -> Result<Vec<f64>, FourierError> {
+        let i = Complex64::i();
+        let discount = f64::exp(-self.risk_free_rate * self.maturity);
+        let log_forward = model.characteristic_function(-i).re.ln();
+
+        // Log-strike grid k_j = ln F - b + λj, with λη = 2π/N
+        let lambda = 2.0 * PI / (points as f64 * spacing);
+        let lower = log_forward - 0.5 * points as f64 * lambda;
+
+        let mut values: Vec<Complex64> = (0..points)
+            .map(|j| {
+                let v = j as f64 * spacing;
+                // ψ(v) = e^(-rT) φ(v - (α + 1)i) / (α² + α - v² + i(2α + 1)v)
+                let psi = discount * model.characteristic_function(Complex64::new(v, -(damping + 1.0)))
+                    / Complex64::new(damping * damping + damping - v * v, (2.0 * damping + 1.0) * v);
+
+                // Simpson weights η/3 (3 + (-1)^(j+1) - δ_j0)
+                let simpson = (3.0 + if j % 2 == 0 { -1.0 } else { 1.0 } - if j == 0 { 1.0 } else { 0.0 }) / 3.0;
+                (-i * v * lower).exp() * psi * spacing * simpson
+            })
+            .collect();
+
+        fft(&mut values);
+
+        let grid_prices: Vec<f64> = values
+            .iter()
+            .enumerate()
+            .map(|(j, value)| f64::exp(-damping * (lower + j as f64 * lambda)) / PI * value.re)
+            .collect();
+
+        // Linear interpolation of the log-strike grid onto the requested strikes
+        strikes
+            .iter()
+            .map(|&strike| {
+                let position = (strike.ln() - lower) / lambda;
+                if !(0.0..=(points - 1) as f64).contains(&position) {
+                    return Err(FourierError::StrikeOutsideGrid {
+                        strike,
+                        lower: lower.exp(),
+                        upper: f64::exp(lower + (points - 1) as f64 * lambda),
+                    });
+                }
+                let j = (position.floor() as usize).min(points - 2);
+                let weight = position - j as f64;
+                Ok((1.0 - weight) * grid_prices[j] + weight * grid_prices[j + 1])
+            })
+            .collect()
+    }
+
+    fn cos_call_prices(
+        &self,
+        model: &impl CharacteristicFunction,
+        strikes: &[f64],
+        terms: usize,
+        truncation: f64,
+    ) -> Vec<f64> {
+        let i = Complex64::i();
+        let discount = f64::exp(-self.risk_free_rate * self.maturity);
+        let log_forward = model.characteristic_function(-i).re.ln();
+
+        // Characteristic function of y = ln(S_T / F)
+        let centred = |u: f64| model.characteristic_function(Complex64::new(u, 0.0)) * (-i * u * log_forward).exp();
+
+        // First two cumulants of y from finite differences of ln φ at zero
+        let h = 1e-4;
+        let (log_up, log_down) = (centred(h).ln(), centred(-h).ln());
+        let c1 = (log_up.im - log_down.im) / (2.0 * h);
+        let c2 = -(log_up.re + log_down.re) / (h * h);
+
+        let a = c1 - truncation * c2.sqrt();
+        let b = c1 + truncation * c2.sqrt();
+        let width = b - a;
+
+        // Cosine coefficients of the call payoff (e^y - 1)^+ on [a, b]: U_k = 2/(b - a) (χ_k(0, b) - ψ_k(0, b))
+        let payoff_coefficients: Vec<f64> = (0..terms)
+            .map(|k| {
+                let omega = k as f64 * PI / width;
+                let chi = (f64::cos(omega * (b - a)) * b.exp() - f64::cos(-omega * a)
+                    + omega * f64::sin(omega * (b - a)) * b.exp() - omega * f64::sin(-omega * a))
+                    / (1.0 + omega * omega);
+                let psi = if k == 0 { b } else { (f64::sin(omega * (b - a)) - f64::sin(-omega * a)) / omega };
+                2.0 / width * (chi - psi)
+            })
+            .collect();
+
+        let characteristic_values: Vec<Complex64> = (0..terms)
+            .map(|k| centred(k as f64 * PI / width))
+            .collect();
+
+        strikes
+            .iter()
+            .map(|strike| {
+                let x = log_forward - strike.ln();
+                let series: f64 = (0..terms)
+                    .map(|k| {
+                        let omega = k as f64 * PI / width;
+                        let term = (characteristic_values[k] * (i * omega * (x - a)).exp()).re * payoff_coefficients[k];
+                        // The first term of the cosine series is halved
+                        if k == 0 { 0.5 * term } else { term }
+                    })
+                    .sum();
+                discount * strike * series
+            })
+            .collect()
+    }
+}
+
+// In-place iterative radix-2 FFT computing Σ_j x_j e^(-2πijk/N)
+fn fft(values: &mut [Complex64]) {
+    let n = values.len();
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+    while length <= n {
+        let root = Complex64::from_polar(1.0, -2.0 * PI / length as f64);
+        for start in (0..n).step_by(length) {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..length / 2 {
+                let even = values[start + k];
+                let odd = values[start + k + length / 2] * w;
+                values[start + k] = even + odd;
+                values[start + k + length / 2] = even - odd;
+                w *= root;
+            }
+        }
+        length <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlackScholesModel, OptionPricingModel};
+
+    const STRIKES: [f64; 5] = [80.0, 100.0, 120.0, 140.0, 160.0];
+
+    fn black_scholes_model(strike: f64) -> BlackScholesModel {
+        BlackScholesModel {
+            underlying: 120.0,
+            strike,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+        }
+    }
+
+    fn assert_matches_black_scholes(prices: &[f64], option_type: OptionType, tolerance: f64) {
+        for (price, strike) in prices.iter().zip(STRIKES) {
+            let exact = black_scholes_model(strike).price(option_type);
+            assert!((price - exact).abs() < tolerance, "{:?} K={}: {} vs {}", option_type, strike, price, exact);
+        }
+    }
+
+    #[test]
+    fn gil_pelaez_matches_black_scholes() {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let prices: Vec<f64> = STRIKES
+                .iter()
+                .map(|&strike| gil_pelaez_price(&black_scholes_model(strike), strike, 1.0, 0.05, option_type))
+                .collect();
+            assert_matches_black_scholes(&prices, option_type, 1e-6);
+        }
+    }
+
+    #[test]
+    fn carr_madan_and_cos_match_black_scholes() {
+        for (method, tolerance) in [(FourierMethod::CARR_MADAN, 1e-2), (FourierMethod::COS, 1e-8)] {
+            let engine = FourierEngine { maturity: 1.0, risk_free_rate: 0.05, method };
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let prices = engine.price_strikes(&black_scholes_model(100.0), &STRIKES, option_type).unwrap();
+                assert_matches_black_scholes(&prices, option_type, tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn carr_madan_rejects_invalid_points() {
+        for points in [0, 1, 1000] {
+            let engine = FourierEngine {
+                maturity: 1.0,
+                risk_free_rate: 0.05,
+                method: FourierMethod::CarrMadan { points, spacing: 0.25, damping: 1.5 },
+            };
+            let result = engine.price_strikes(&black_scholes_model(100.0), &STRIKES, OptionType::Call);
+            assert_eq!(result, Err(FourierError::InvalidPoints(points)));
+        }
+    }
+
+    #[test]
+    fn carr_madan_rejects_strikes_outside_the_grid() {
+        // The log-strike grid spans ln F ± 0.79 with λ = 2π/(64 × 4)
+        let engine = FourierEngine {
+            maturity: 1.0,
+            risk_free_rate: 0.05,
+            method: FourierMethod::CarrMadan { points: 64, spacing: 4.0, damping: 1.5 },
+        };
+        for strike in [20.0, 1000.0] {
+            let result = engine.price_strikes(&black_scholes_model(100.0), &[100.0, strike], OptionType::Call);
+            assert!(matches!(result, Err(FourierError::StrikeOutsideGrid { strike: rejected, .. }) if rejected == strike));
+        }
+        assert!(engine.price_strikes(&black_scholes_model(100.0), &[100.0, 150.0], OptionType::Call).is_ok());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{OptionPricingModel, OptionType};
+use crate::distributions::{normal_cdf, normal_pdf};
+
+// Gap option: a call pays S - K when S ends above the trigger strike and a put pays K - S when S
+// ends below it. With the payment strike above the trigger the payoff can be negative.
+#[derive(Clone)]
+pub struct GapOptionModel {
+    pub underlying: f64,
+    pub strike: f64,         // K1: Payment strike
+    pub trigger_strike: f64, // K2: Strike deciding whether the option pays
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+}
+
+impl GapOptionModel {
+    // d1 and d2 are taken at the trigger strike
+    fn calculate_d1_d2(&self) -> (f64, f64) {
+        let sigma_sqrt_t = self.volatility * f64::sqrt(self.maturity);
+        let d1 = (f64::ln(self.underlying / self.trigger_strike)
+            + (self.risk_free_rate - self.dividend_yield + 0.5 * self.volatility.powi(2)) * self.maturity)
+            / sigma_sqrt_t;
+        (d1, d1 - sigma_sqrt_t)
+    }
+}
+
+impl OptionPricingModel for GapOptionModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let (d1, d2) = self.calculate_d1_d2();
+        let asset = self.underlying * f64::exp(-self.dividend_yield * self.maturity);
+        let discounted_strike = self.strike * f64::exp(-self.risk_free_rate * self.maturity);
+
+        match option_type {
+            OptionType::Call => asset * normal_cdf(d1) - discounted_strike * normal_cdf(d2),
+            OptionType::Put => discounted_strike * normal_cdf(-d2) - asset * normal_cdf(-d1),
+        }
+    }
+
+    // A vanilla delta plus the jump (K2 - K1) at the trigger: e^(-qT) N(d1) + e^(-rT) (K2 - K1) n(d2) / (Sσ√T)
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let (d1, d2) = self.calculate_d1_d2();
+        let sigma_sqrt_t = self.volatility * f64::sqrt(self.maturity);
+        let jump = f64::exp(-self.risk_free_rate * self.maturity) * (self.trigger_strike - self.strike) * normal_pdf(d2)
+            / (self.underlying * sigma_sqrt_t);
+        let dividend_discount = f64::exp(-self.dividend_yield * self.maturity);
+
+        match option_type {
+            OptionType::Call => dividend_discount * normal_cdf(d1) + jump,
+            OptionType::Put => -dividend_discount * normal_cdf(-d1) + jump,
+        }
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
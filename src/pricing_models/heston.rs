@@ -0,0 +1,99 @@
+use crate::{OptionPricingModel, OptionType, CharacteristicFunction};
+use crate::pricing_models::fourier::{gil_pelaez_price, exercise_probabilities};
+use num_complex::Complex64;
+
+#[derive(Clone)]
+pub struct HestonModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub risk_free_rate: f64,
+    pub initial_variance: f64,  // v0: Variance at time zero
+    pub mean_reversion: f64,    // κ: Speed at which variance reverts to its long-run level
+    pub long_run_variance: f64, // θ: Long-run variance level
+    pub vol_of_vol: f64,        // σ: Volatility of the variance process
+    pub correlation: f64,       // ρ: Correlation between the asset and variance Brownian motions
+}
+
+impl CharacteristicFunction for HestonModel {
+    // Characteristic function of ln(S_T) in the "little Heston trap" formulation
+    // (Albrecher et al.), which avoids branch cuts of the complex logarithm
+    fn characteristic_function(&self, u: Complex64) -> Complex64 {
+        let i = Complex64::i();
+        let sigma = self.vol_of_vol;
+        let t = self.maturity;
+
+        let xi = self.mean_reversion - sigma * self.correlation * i * u;
+        let d = (xi * xi + sigma * sigma * (u * u + i * u)).sqrt();
+        let g = (xi - d) / (xi + d);
+        let exp_dt = (-d * t).exp();
+
+        let c = i * u * (self.underlying.ln() + self.risk_free_rate * t)
+            + self.mean_reversion * self.long_run_variance / (sigma * sigma)
+                * ((xi - d) * t - 2.0 * ((1.0 - g * exp_dt) / (1.0 - g)).ln());
+        let big_d = (xi - d) / (sigma * sigma) * (1.0 - exp_dt) / (1.0 - g * exp_dt);
+
+        (c + big_d * self.initial_variance).exp()
+    }
+}
+
+impl OptionPricingModel for HestonModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        gil_pelaez_price(self, self.strike, self.maturity, self.risk_free_rate, option_type)
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let (p1, _) = exercise_probabilities(self, self.strike);
+        match option_type {
+            OptionType::Call => p1,
+            OptionType::Put => p1 - 1.0,
+        }
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let delta_up = model_up.delta(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let delta_down = model_down.delta(option_type);
+
+        (delta_up - delta_down) / (2.0 * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        // Sensitivity to the initial volatility √v0
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.initial_variance = (self.initial_variance.sqrt() + epsilon).powi(2);
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{OptionType, ExerciseStyle, Payoff};
+
+// Payoff and exercise handling shared by the binomial and trinomial trees and the finite difference grid
+
+pub(crate) fn option_payoff(strike: f64, asset_price: f64, option_type: OptionType) -> f64 {
+    match option_type {
+        OptionType::Call => f64::max(0.0, asset_price - strike),
+        OptionType::Put => f64::max(0.0, strike - asset_price),
+    }
+}
+
+// Asset price at which the payoff is centred: K for vanilla options, K^(1/n) for power options and
+// the trigger strike for gap options
+pub(crate) fn payoff_strike(payoff: Payoff, strike: f64) -> f64 {
+    match payoff {
+        Payoff::Vanilla => strike,
+        Payoff::Power { exponent } => strike.powf(1.0 / exponent),
+        Payoff::Gap { trigger_strike } => trigger_strike,
+    }
+}
+
+pub(crate) fn payoff_value(payoff: Payoff, strike: f64, asset_price: f64, option_type: OptionType) -> f64 {
+    match (payoff, option_type) {
+        (Payoff::Vanilla, _) => option_payoff(strike, asset_price, option_type),
+        (Payoff::Power { exponent }, _) => option_payoff(strike, asset_price.powf(exponent), option_type),
+        (Payoff::Gap { trigger_strike }, OptionType::Call) =>
+            if asset_price > trigger_strike { asset_price - strike } else { 0.0 },
+        (Payoff::Gap { trigger_strike }, OptionType::Put) =>
+            if asset_price < trigger_strike { strike - asset_price } else { 0.0 },
+    }
+}
+
+// Value at a node: the discounted continuation value, or the intrinsic value if it is
+// larger and the option can be exercised early
+pub(crate) fn node_value(
+    continuation: f64,
+    payoff: Payoff,
+    strike: f64,
+    asset_price: f64,
+    option_type: OptionType,
+    exercise: ExerciseStyle,
+) -> f64 {
+    match exercise {
+        ExerciseStyle::European => continuation,
+        ExerciseStyle::American => f64::max(continuation, payoff_value(payoff, strike, asset_price, option_type)),
+    }
+}
+
+// Tracks the exercised node closest to the continuation region within one time step
+pub(crate) struct CriticalPrice {
+    payoff: Payoff,
+    strike: f64,
+    option_type: OptionType,
+    exercise: ExerciseStyle,
+    current: Option<f64>,
+}
+
+impl CriticalPrice {
+    pub(crate) fn new(payoff: Payoff, strike: f64, option_type: OptionType, exercise: ExerciseStyle) -> Self {
+        CriticalPrice { payoff, strike, option_type, exercise, current: None }
+    }
+
+    // A node is exercised when its value equals a positive intrinsic value
+    pub(crate) fn record(&mut self, value: f64, asset_price: f64) {
+        let intrinsic = payoff_value(self.payoff, self.strike, asset_price, self.option_type);
+        if self.exercise == ExerciseStyle::European || intrinsic <= 0.0 || value > intrinsic {
+            return;
+        }
+
+        // Puts are exercised below the boundary and calls above it
+        self.current = Some(match (self.current, self.option_type) {
+            (None, _) => asset_price,
+            (Some(critical), OptionType::Put) => critical.max(asset_price),
+            (Some(critical), OptionType::Call) => critical.min(asset_price),
+        });
+    }
+
+    // Close the current time step, appending its critical price if any node was exercised
+    pub(crate) fn push_step(&mut self, boundary: &mut Vec<(f64, f64)>, time: f64) {
+        if let Some(critical) = self.current.take() {
+            boundary.push((time, critical));
+        }
+    }
+}
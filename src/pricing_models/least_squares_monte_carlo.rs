@@ -0,0 +1,407 @@
+use crate::{OptionPricingModel, OptionType};
+use crate::pricing_models::lattice::option_payoff;
+use rand_distr::{Normal, Distribution};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+// Regressors for the continuation value, evaluated at the moneyness x = S/K. Both use a constant
+// plus `basis_degree` further functions.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum BasisFunctions {
+    // Constant plus the weighted Laguerre polynomials e^(-x/2) L_0(x), ..., e^(-x/2) L_(n-1)(x), as
+    // in Longstaff-Schwartz
+    Laguerre,
+    // Monomials 1, x, ..., x^n
+    Polynomial,
+}
+
+// Longstaff-Schwartz (2001) least-squares Monte Carlo for Bermudan and American options under GBM.
+// The price is a lower bound: the exercise rule is fitted on one set of paths and applied to an
+// independent set.
+#[derive(Clone)]
+pub struct LeastSquaresMonteCarloModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub simulations: u32,
+    pub exercise_dates: u32,  // Number of equally spaced exercise dates; many dates approximate American exercise
+    pub basis: BasisFunctions,
+    pub basis_degree: usize,  // n: Number of basis functions besides the constant
+    pub seed: u64,            // Paths are regenerated from this seed, so bumped greeks share random numbers
+}
+
+// Regression coefficients of the discounted continuation value at each exercise date
+struct ExercisePolicy {
+    coefficients: Vec<Vec<f64>>, // Empty where too few paths were in the money to regress
+}
+
+impl LeastSquaresMonteCarloModel {
+    fn dt(&self) -> f64 {
+        self.maturity / self.exercise_dates as f64
+    }
+
+    // Exact GBM step over one exercise interval
+    fn step(&self, price: f64, z: f64) -> f64 {
+        let dt = self.dt();
+        price * f64::exp((self.risk_free_rate - 0.5 * self.volatility.powi(2)) * dt + self.volatility * f64::sqrt(dt) * z)
+    }
+
+    // Simulated prices at dates 0..=N for each path
+    fn simulate_paths(&self, rng: &mut impl Rng) -> Vec<Vec<f64>> {
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+
+        (0..self.simulations)
+            .map(|_| {
+                let mut path = Vec::with_capacity(self.exercise_dates as usize + 1);
+                let mut price = self.underlying;
+                path.push(price);
+                for _ in 0..self.exercise_dates {
+                    price = self.step(price, normal_dist.sample(rng));
+                    path.push(price);
+                }
+                path
+            })
+            .collect()
+    }
+
+    // Payoff at date k discounted to today
+    fn discounted_payoff(&self, date: u32, asset_price: f64, option_type: OptionType) -> f64 {
+        option_payoff(self.strike, asset_price, option_type) * f64::exp(-self.risk_free_rate * date as f64 * self.dt())
+    }
+
+    fn basis_values(&self, asset_price: f64) -> Vec<f64> {
+        let x = asset_price / self.strike;
+        let mut values = vec![1.0];
+
+        match self.basis {
+            BasisFunctions::Laguerre => {
+                // (n + 1) L_(n+1) = (2n + 1 - x) L_n - n L_(n-1), with L_0 = 1 and L_1 = 1 - x
+                let mut laguerre = vec![1.0, 1.0 - x];
+                for n in 1..self.basis_degree.saturating_sub(1) {
+                    let next = ((2 * n + 1) as f64 - x) * laguerre[n] - n as f64 * laguerre[n - 1];
+                    laguerre.push(next / (n + 1) as f64);
+                }
+                let weight = f64::exp(-0.5 * x);
+                values.extend(laguerre.iter().take(self.basis_degree).map(|l| weight * l));
+            }
+            BasisFunctions::Polynomial => {
+                for n in 1..=self.basis_degree {
+                    values.push(x.powi(n as i32));
+                }
+            }
+        }
+
+        values
+    }
+
+    // Backward induction on a fresh set of paths, regressing realised discounted cash flows on
+    // the basis functions over the in-the-money paths at each date
+    fn fit_policy(&self, option_type: OptionType, rng: &mut impl Rng) -> ExercisePolicy {
+        let n = self.exercise_dates;
+        let paths = self.simulate_paths(rng);
+        let mut cash_flows: Vec<f64> = paths.iter()
+            .map(|path| self.discounted_payoff(n, path[n as usize], option_type))
+            .collect();
+
+        let mut coefficients = vec![Vec::new(); n as usize + 1];
+
+        for date in (1..n).rev() {
+            let in_the_money: Vec<usize> = (0..paths.len())
+                .filter(|&i| option_payoff(self.strike, paths[i][date as usize], option_type) > 0.0)
+                .collect();
+
+            let regressors: Vec<Vec<f64>> = in_the_money.iter()
+                .map(|&i| self.basis_values(paths[i][date as usize]))
+                .collect();
+            let targets: Vec<f64> = in_the_money.iter().map(|&i| cash_flows[i]).collect();
+
+            if in_the_money.len() <= self.basis_degree + 1 {
+                continue;
+            }
+            coefficients[date as usize] = least_squares(&regressors, &targets);
+
+            // Exercise where the immediate payoff beats the fitted continuation value
+            for (&i, row) in in_the_money.iter().zip(&regressors) {
+                let exercise = self.discounted_payoff(date, paths[i][date as usize], option_type);
+                if exercise >= dot(&coefficients[date as usize], row) {
+                    cash_flows[i] = exercise;
+                }
+            }
+        }
+
+        ExercisePolicy { coefficients }
+    }
+
+    fn should_exercise(&self, policy: &ExercisePolicy, date: u32, asset_price: f64, option_type: OptionType) -> bool {
+        let exercise = self.discounted_payoff(date, asset_price, option_type);
+        if exercise <= 0.0 {
+            return false;
+        }
+        if date == self.exercise_dates {
+            return true;
+        }
+
+        let coefficients = &policy.coefficients[date as usize];
+        !coefficients.is_empty() && exercise >= dot(coefficients, &self.basis_values(asset_price))
+    }
+
+    // Discounted cash flow from following the policy after `date`, starting at `asset_price`
+    fn policy_cash_flow(
+        &self,
+        policy: &ExercisePolicy,
+        date: u32,
+        asset_price: f64,
+        option_type: OptionType,
+        rng: &mut impl Rng,
+    ) -> f64 {
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+        let mut price = asset_price;
+
+        for next_date in date + 1..=self.exercise_dates {
+            price = self.step(price, normal_dist.sample(rng));
+            if self.should_exercise(policy, next_date, price, option_type) {
+                return self.discounted_payoff(next_date, price, option_type);
+            }
+        }
+
+        0.0
+    }
+
+    // Average policy cash flow over independent continuations from one node
+    fn continuation_value(
+        &self,
+        policy: &ExercisePolicy,
+        date: u32,
+        asset_price: f64,
+        option_type: OptionType,
+        simulations: u32,
+        rng: &mut impl Rng,
+    ) -> f64 {
+        let total: f64 = (0..simulations)
+            .map(|_| self.policy_cash_flow(policy, date, asset_price, option_type, rng))
+            .sum();
+        total / simulations as f64
+    }
+
+    // Andersen-Broadie (2004) duality upper bound E[max_k (h_k - M_k)], where the martingale M is
+    // built from the fitted policy's values along each outer path, estimated with nested
+    // simulations. The gap to `price` measures how far the exercise rule is from optimal.
+    pub fn dual_upper_bound(&self, option_type: OptionType, outer_paths: u32, inner_simulations: u32) -> f64 {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let policy = self.fit_policy(option_type, &mut rng);
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+        let n = self.exercise_dates;
+
+        // E[L_1] is shared by every outer path, so estimate it with the full simulation count
+        let initial_continuation =
+            self.continuation_value(&policy, 0, self.underlying, option_type, self.simulations, &mut rng);
+
+        let mut total = 0.0;
+        for _ in 0..outer_paths {
+            let mut price = self.underlying;
+            let mut martingale = 0.0;
+            let mut max_gap = self.discounted_payoff(0, price, option_type);
+            let mut continuation = initial_continuation;
+
+            for date in 1..=n {
+                price = self.step(price, normal_dist.sample(&mut rng));
+                let exercise = self.discounted_payoff(date, price, option_type);
+
+                // Value of the policy at this node, and its expectation from the previous date:
+                // M_k = M_(k-1) + L_k - E_(k-1)[L_k]
+                let next_continuation = if date < n {
+                    self.continuation_value(&policy, date, price, option_type, inner_simulations, &mut rng)
+                } else {
+                    0.0
+                };
+                let value = if self.should_exercise(&policy, date, price, option_type) {
+                    exercise
+                } else {
+                    next_continuation
+                };
+
+                martingale += value - continuation;
+                max_gap = f64::max(max_gap, exercise - martingale);
+                continuation = next_continuation;
+            }
+
+            total += max_gap;
+        }
+
+        total / outer_paths as f64
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+// Ordinary least squares through the normal equations XᵀX β = Xᵀy, solved by Gaussian
+// elimination with partial pivoting
+fn least_squares(regressors: &[Vec<f64>], targets: &[f64]) -> Vec<f64> {
+    let size = regressors[0].len();
+    let mut matrix = vec![vec![0.0; size + 1]; size];
+
+    for (row, &target) in regressors.iter().zip(targets) {
+        for i in 0..size {
+            for j in 0..size {
+                matrix[i][j] += row[i] * row[j];
+            }
+            matrix[i][size] += row[i] * target;
+        }
+    }
+
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))
+            .unwrap();
+        matrix.swap(column, pivot);
+
+        if matrix[column][column].abs() < f64::EPSILON {
+            continue;
+        }
+        let (upper, lower) = matrix.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for row in lower.iter_mut() {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(column) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut coefficients = vec![0.0; size];
+    for i in (0..size).rev() {
+        if matrix[i][i].abs() < f64::EPSILON {
+            continue;
+        }
+        let known: f64 = (i + 1..size).map(|j| matrix[i][j] * coefficients[j]).sum();
+        coefficients[i] = (matrix[i][size] - known) / matrix[i][i];
+    }
+
+    coefficients
+}
+
+impl OptionPricingModel for LeastSquaresMonteCarloModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let policy = self.fit_policy(option_type, &mut rng);
+
+        // Value the fitted rule on independent paths, so the estimate is biased low
+        let paths = self.simulate_paths(&mut rng);
+        let total: f64 = paths.iter()
+            .map(|path| {
+                (1..=self.exercise_dates)
+                    .find(|&date| self.should_exercise(&policy, date, path[date as usize], option_type))
+                    .map_or(0.0, |date| self.discounted_payoff(date, path[date as usize], option_type))
+            })
+            .sum();
+
+        f64::max(total / self.simulations as f64, option_payoff(self.strike, self.underlying, option_type))
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01 * self.underlying; // Relative bump, so exercise decisions average out
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.05 * self.underlying;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinomialModel, BinomialTree, ConvergenceAcceleration, ExerciseStyle, Payoff};
+
+    #[test]
+    fn bounds_bracket_the_american_binomial_put() {
+        let binomial = BinomialModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            steps: 1001,
+            tree: BinomialTree::CoxRossRubinstein,
+            exercise: ExerciseStyle::American,
+            acceleration: ConvergenceAcceleration::BlackScholesRichardson,
+            payoff: Payoff::Vanilla,
+        };
+        let american = binomial.price(OptionType::Put);
+
+        for basis in [BasisFunctions::Laguerre, BasisFunctions::Polynomial] {
+            let model = LeastSquaresMonteCarloModel {
+                underlying: 100.0,
+                strike: 100.0,
+                maturity: 1.0,
+                volatility: 0.2,
+                risk_free_rate: 0.05,
+                simulations: 20_000,
+                exercise_dates: 20,
+                basis,
+                basis_degree: 3,
+                seed: 42,
+            };
+            let lower = model.price(OptionType::Put);
+            let upper = model.dual_upper_bound(OptionType::Put, 100, 100);
+            assert!(lower <= upper, "{:?}: {} > {}", basis, lower, upper);
+            assert!((lower - american).abs() < 0.1, "{:?}: {} vs {}", basis, lower, american);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel, CharacteristicFunction};
+use num_complex::Complex64;
+
+#[derive(Clone)]
+pub struct MertonJumpDiffusionModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub jump_intensity: f64,  // λ: Expected number of jumps per year
+    pub jump_mean: f64,       // μ_J: Mean of the log jump size
+    pub jump_volatility: f64, // δ: Standard deviation of the log jump size
+}
+
+impl MertonJumpDiffusionModel {
+    // Maximum number of Poisson terms in the series expansion
+    const MAX_TERMS: u32 = 100;
+    // Stop once the remaining Poisson probability mass is below this
+    const TOLERANCE: f64 = 1e-12;
+
+    // Expected relative jump size: k = E[J - 1] = e^(μ_J + δ²/2) - 1
+    fn expected_jump(&self) -> f64 {
+        f64::exp(self.jump_mean + 0.5 * self.jump_volatility.powi(2)) - 1.0
+    }
+
+    // Black-Scholes model conditional on exactly n jumps before maturity
+    fn conditional_model(&self, n: u32) -> BlackScholesModel {
+        let k = self.expected_jump();
+        let n = n as f64;
+
+        // σ_n² = σ² + nδ²/T and r_n = r - λk + n ln(1 + k)/T
+        let volatility = f64::sqrt(self.volatility.powi(2) + n * self.jump_volatility.powi(2) / self.maturity);
+        let risk_free_rate = self.risk_free_rate - self.jump_intensity * k
+            + n * f64::ln(1.0 + k) / self.maturity;
+
+        BlackScholesModel {
+            underlying: self.underlying,
+            strike: self.strike,
+            maturity: self.maturity,
+            volatility,
+            risk_free_rate,
+        }
+    }
+
+    // Poisson-weighted sum of a Black-Scholes quantity over the number of jumps
+    fn poisson_series<F>(&self, value: F) -> f64
+    where
+        F: Fn(&BlackScholesModel) -> f64,
+    {
+        // Jump-adjusted intensity: λ' = λ(1 + k)
+        let lambda_t = self.jump_intensity * (1.0 + self.expected_jump()) * self.maturity;
+
+        let mut weight = f64::exp(-lambda_t);
+        let mut cumulative_weight = 0.0;
+        let mut total = 0.0;
+
+        for n in 0..Self::MAX_TERMS {
+            total += weight * value(&self.conditional_model(n));
+            cumulative_weight += weight;
+
+            if 1.0 - cumulative_weight < Self::TOLERANCE {
+                break;
+            }
+
+            // P(N = n + 1) = P(N = n) * λ'T / (n + 1)
+            weight *= lambda_t / (n + 1) as f64;
+        }
+
+        total
+    }
+}
+
+// Characteristic function of ln(S_T): the compensated GBM part times the compound Poisson part
+// exp(λT(e^(iuμ_J - δ²u²/2) - 1))
+impl CharacteristicFunction for MertonJumpDiffusionModel {
+    fn characteristic_function(&self, u: Complex64) -> Complex64 {
+        let i = Complex64::i();
+        let t = self.maturity;
+        let drift = self.underlying.ln()
+            + (self.risk_free_rate - self.jump_intensity * self.expected_jump() - 0.5 * self.volatility.powi(2)) * t;
+        let jump_exponent = (i * u * self.jump_mean - 0.5 * self.jump_volatility.powi(2) * u * u).exp() - 1.0;
+
+        (i * u * drift - 0.5 * self.volatility.powi(2) * t * u * u + self.jump_intensity * t * jump_exponent).exp()
+    }
+}
+
+impl OptionPricingModel for MertonJumpDiffusionModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        self.poisson_series(|model| model.price(option_type))
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        // The conditional drift and volatility do not depend on S, so delta is the weighted sum
+        self.poisson_series(|model| model.delta(option_type))
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        self.poisson_series(|model| model.gamma(option_type))
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
@@ -1,6 +1,107 @@
-use crate::{OptionPricingModel, OptionType};
-use rand_distr::{Normal, Distribution};
+use crate::{OptionPricingModel, OptionType, Payoff};
+use crate::pricing_models::lattice::payoff_value;
+use crate::pricing_models::autocallable::{Autocallable, AutocallableReport, Settlement, ScheduleError};
+use crate::pricing_models::barrier::{ParisianBarrier, BarrierKnock};
+use crate::pricing_models::range_accrual::RangeAccrual;
+use rand_distr::{Normal, Poisson, Gamma, InverseGaussian, Distribution};
 use rand::Rng;
+use std::fmt;
+
+// Stochastic process driving the simulated underlying
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Dynamics {
+    // Geometric Brownian motion, as in Black-Scholes
+    GeometricBrownian,
+    // GBM with compound-Poisson lognormal jumps
+    MertonJumpDiffusion {
+        jump_intensity: f64,
+        jump_mean: f64,
+        jump_volatility: f64,
+    },
+    // Heston stochastic variance, simulated with Andersen's QE scheme. `volatility` is ignored, and
+    // vega is the sensitivity to the initial volatility √v0 as in `HestonModel`.
+    Heston {
+        initial_variance: f64,
+        mean_reversion: f64,
+        long_run_variance: f64,
+        vol_of_vol: f64,
+        correlation: f64,
+    },
+    // Constant elasticity of variance, dS = rS dt + σ S^α dW, absorbed at zero
+    Cev {
+        elasticity: f64,
+    },
+    // Variance Gamma: Brownian motion with drift subordinated to a gamma process (σ is `volatility`)
+    VarianceGamma {
+        variance_rate: f64,
+        drift: f64,
+    },
+    // Normal Inverse Gaussian: Brownian motion with drift subordinated to an inverse Gaussian process
+    NormalInverseGaussian {
+        alpha: f64,
+        beta: f64,
+        scale: f64,
+    },
+    // GBM for a foreign asset paid at a fixed exchange rate, with the quanto drift r_f - q - ρσσ_X
+    // under the domestic measure; prices are per unit of the fixed rate. The dividend yield here
+    // replaces the model's `dividend_yield`.
+    Quanto {
+        foreign_rate: f64,
+        dividend_yield: f64,
+        fx_volatility: f64,
+        fx_correlation: f64,
+    },
+}
+
+// Cliquet on the returns R_i = S(t_i)/S(t_(i-1)) - 1 between equally spaced reset dates, paying
+// notional × min(max(Σ_i min(max(R_i, local floor), local cap), global floor), global cap) at
+// maturity. Use ±infinity for absent caps and floors; a ratchet has a local floor of zero.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Cliquet {
+    pub resets: u32,
+    pub local_floor: f64,
+    pub local_cap: f64,
+    pub global_floor: f64,
+    pub global_cap: f64,
+    pub notional: f64,
+}
+
+// Payoff at maturity on the realised variance σ_R² = (1/T) Σ_i ln(S_i/S_(i-1))² over the
+// simulation steps, so `steps` sets the sampling frequency
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum RealizedVariancePayoff {
+    // notional × (σ_R² - strike), with the strike in variance units
+    VarianceSwap { strike: f64, notional: f64 },
+    // notional × (σ_R - strike), with the strike in volatility units
+    VolatilitySwap { strike: f64, notional: f64 },
+}
+
+// Mean and variance of the realised variance over the paths that were not absorbed at zero
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct RealizedVarianceMoments {
+    pub mean: f64,
+    pub variance: f64,
+    pub absorbed_paths: u32, // Paths dropped because their realised variance is infinite
+}
+
+// Fewer than two paths with finite realised variance, too few for the sample variance
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct InsufficientPathsError {
+    pub usable_paths: u32,
+    pub absorbed_paths: u32,
+}
+
+impl fmt::Display for InsufficientPathsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} paths with finite realised variance ({} absorbed at zero), at least 2 are needed",
+            self.usable_paths, self.absorbed_paths
+        )
+    }
+}
+
+impl std::error::Error for InsufficientPathsError {}
 
 #[derive(Clone)]
 pub struct MonteCarloModel {
@@ -9,42 +110,391 @@ pub struct MonteCarloModel {
     pub maturity: f64,
     pub volatility: f64,
     pub risk_free_rate: f64,
+    pub dividend_yield: f64, // q: Continuous dividend yield, so the asset drifts at r - q
     pub simulations: u32,
     pub steps: u32,
+    pub dynamics: Dynamics,
+    pub payoff: Payoff,
 }
 
 impl MonteCarloModel {
+    // Vanilla option on geometric Brownian motion without dividends. Set `dividend_yield`,
+    // `dynamics` and `payoff` with struct update syntax for other assets, processes and payoffs.
+    pub fn new(underlying: f64, strike: f64, maturity: f64, volatility: f64, risk_free_rate: f64,
+               simulations: u32, steps: u32) -> Self {
+        MonteCarloModel {
+            underlying,
+            strike,
+            maturity,
+            volatility,
+            risk_free_rate,
+            dividend_yield: 0.0,
+            simulations,
+            steps,
+            dynamics: Dynamics::GeometricBrownian,
+            payoff: Payoff::Vanilla,
+        }
+    }
+
+    // Risk-neutral drift of the asset, r - q
+    fn carry(&self) -> f64 {
+        self.risk_free_rate - self.dividend_yield
+    }
 
     fn cap_value(&self, value: f64, min: f64, max: f64) -> f64 {
         value.clamp(min, max)
     }
 
-    // Function to simulate a single path of the underlying asset price
-    fn simulate_path(&self, rng: &mut impl Rng) -> f64 {
+    // Function to simulate a single path of the underlying asset price, returning the price today
+    // and after each time step
+    fn simulate_path(&self, rng: &mut impl Rng) -> Vec<f64> {
+        let mut path = Vec::with_capacity(self.steps as usize + 1);
+        self.visit_path(rng, &mut |price| path.push(price));
+        path
+    }
+
+    // Final price of a single simulated path, without storing the path
+    fn simulate_final_price(&self, rng: &mut impl Rng) -> f64 {
+        let mut final_price = self.underlying;
+        self.visit_path(rng, &mut |price| final_price = price);
+        final_price
+    }
+
+    // Simulate a single path, passing the price today and after each time step to `visit`
+    fn visit_path(&self, rng: &mut impl Rng, visit: &mut impl FnMut(f64)) {
+        match self.dynamics {
+            Dynamics::GeometricBrownian => self.simulate_gbm_path(rng, self.carry(), visit),
+            Dynamics::MertonJumpDiffusion { jump_intensity, jump_mean, jump_volatility } =>
+                self.simulate_jump_path(rng, jump_intensity, jump_mean, jump_volatility, visit),
+            Dynamics::Heston { initial_variance, mean_reversion, long_run_variance, vol_of_vol, correlation } =>
+                self.simulate_heston_path(rng, initial_variance, mean_reversion, long_run_variance, vol_of_vol, correlation, visit),
+            Dynamics::Cev { elasticity } => self.simulate_cev_path(rng, elasticity, visit),
+            Dynamics::VarianceGamma { variance_rate, drift } =>
+                self.simulate_variance_gamma_path(rng, variance_rate, drift, visit),
+            Dynamics::NormalInverseGaussian { alpha, beta, scale } =>
+                self.simulate_nig_path(rng, alpha, beta, scale, visit),
+            Dynamics::Quanto { foreign_rate, dividend_yield, fx_volatility, fx_correlation } =>
+                self.simulate_gbm_path(rng, foreign_rate - dividend_yield - fx_correlation * self.volatility * fx_volatility, visit),
+        }
+    }
+
+    fn simulate_gbm_path(&self, rng: &mut impl Rng, drift: f64, visit: &mut impl FnMut(f64)) {
         let dt = self.maturity / self.steps as f64;
         let normal_dist = Normal::new(0.0, 1.0).unwrap();
 
         // Start at the current underlying price
         let mut price = self.underlying;
+        visit(price);
 
         // Simulate the price evolution over each time step
         for _ in 0..self.steps {
             let z: f64 = normal_dist.sample(rng);
-            price *= f64::exp((self.risk_free_rate - 0.5 * self.volatility.powi(2)) * dt
+            price *= f64::exp((drift - 0.5 * self.volatility.powi(2)) * dt
                 + self.volatility * f64::sqrt(dt) * z);
+            visit(price);
+        }
+    }
+
+    // Simulate a GBM path with lognormal jumps arriving as a Poisson process
+    fn simulate_jump_path(&self, rng: &mut impl Rng, jump_intensity: f64, jump_mean: f64, jump_volatility: f64, visit: &mut impl FnMut(f64)) {
+        let dt = self.maturity / self.steps as f64;
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+
+        // Compensate the drift so the discounted price stays a martingale: k = E[J - 1]
+        let k = f64::exp(jump_mean + 0.5 * jump_volatility.powi(2)) - 1.0;
+        let drift = (self.carry() - jump_intensity * k - 0.5 * self.volatility.powi(2)) * dt;
+
+        let mut price = self.underlying;
+        visit(price);
+
+        for _ in 0..self.steps {
+            let z: f64 = normal_dist.sample(rng);
+
+            // Number of jumps in this step, N ~ Poisson(λΔt)
+            let jumps = if jump_intensity > 0.0 {
+                Poisson::new(jump_intensity * dt).unwrap().sample(rng)
+            } else {
+                0.0
+            };
+
+            // Sum of N iid N(μ_J, δ²) log jumps is N(Nμ_J, Nδ²)
+            let log_jump = if jumps > 0.0 {
+                let z_jump: f64 = normal_dist.sample(rng);
+                jumps * jump_mean + f64::sqrt(jumps) * jump_volatility * z_jump
+            } else {
+                0.0
+            };
+
+            price *= f64::exp(drift + self.volatility * f64::sqrt(dt) * z + log_jump);
+            visit(price);
+        }
+    }
+
+    // Simulate a Heston path with the Andersen (2008) Quadratic-Exponential scheme
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_heston_path(
+        &self,
+        rng: &mut impl Rng,
+        initial_variance: f64,
+        mean_reversion: f64,
+        long_run_variance: f64,
+        vol_of_vol: f64,
+        correlation: f64,
+        visit: &mut impl FnMut(f64),
+    ) {
+        let dt = self.maturity / self.steps as f64;
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+
+        // Switching level between the quadratic and exponential variance samplers
+        let psi_critical = 1.5;
+        let decay = f64::exp(-mean_reversion * dt);
+        // (1 - e^(-κΔt)) / κ, which tends to Δt as κ → 0
+        let decay_integral = if mean_reversion.abs() < 1e-10 { dt } else { (1.0 - decay) / mean_reversion };
+
+        // Log-price discretisation constants, with central weights γ1 = γ2 = 1/2
+        let (gamma1, gamma2) = (0.5, 0.5);
+        let k0 = -correlation * mean_reversion * long_run_variance / vol_of_vol * dt;
+        let k1 = gamma1 * dt * (mean_reversion * correlation / vol_of_vol - 0.5) - correlation / vol_of_vol;
+        let k2 = gamma2 * dt * (mean_reversion * correlation / vol_of_vol - 0.5) + correlation / vol_of_vol;
+        let k3 = gamma1 * dt * (1.0 - correlation.powi(2));
+        let k4 = gamma2 * dt * (1.0 - correlation.powi(2));
+
+        let mut log_price = self.underlying.ln();
+        let mut variance = initial_variance;
+        visit(self.underlying);
+
+        for _ in 0..self.steps {
+            // Conditional mean and variance of the next variance
+            let m = long_run_variance + (variance - long_run_variance) * decay;
+            let s2 = variance * vol_of_vol.powi(2) * decay * decay_integral
+                + long_run_variance * vol_of_vol.powi(2) * 0.5 * mean_reversion * decay_integral.powi(2);
+            let psi = s2 / m.powi(2);
+
+            let next_variance = if m <= 0.0 {
+                // Without mean reversion, a variance of zero stays at zero
+                0.0
+            } else if psi <= psi_critical {
+                // Quadratic: v' = a(b + Z)²
+                let b2 = 2.0 / psi - 1.0 + f64::sqrt(2.0 / psi) * f64::sqrt(2.0 / psi - 1.0);
+                let a = m / (1.0 + b2);
+                let z: f64 = normal_dist.sample(rng);
+                a * (b2.sqrt() + z).powi(2)
+            } else {
+                // Exponential: point mass at zero plus an exponential tail
+                let p = (psi - 1.0) / (psi + 1.0);
+                let beta = (1.0 - p) / m;
+                let u: f64 = rng.gen();
+                if u <= p { 0.0 } else { f64::ln((1.0 - p) / (1.0 - u)) / beta }
+            };
+
+            let z: f64 = normal_dist.sample(rng);
+            log_price += self.carry() * dt + k0 + k1 * variance + k2 * next_variance
+                + f64::sqrt(k3 * variance + k4 * next_variance) * z;
+            variance = next_variance;
+            visit(log_price.exp());
         }
+    }
 
-        price
+    // Simulate a CEV path with an Euler scheme, treating zero as absorbing
+    fn simulate_cev_path(&self, rng: &mut impl Rng, elasticity: f64, visit: &mut impl FnMut(f64)) {
+        let dt = self.maturity / self.steps as f64;
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+
+        let mut price = self.underlying;
+        visit(price);
+
+        for step in 0..self.steps {
+            let z: f64 = normal_dist.sample(rng);
+            price += self.carry() * price * dt
+                + self.volatility * price.powf(elasticity) * f64::sqrt(dt) * z;
+
+            if price <= 0.0 {
+                (step..self.steps).for_each(|_| visit(0.0));
+                return;
+            }
+            visit(price);
+        }
+    }
+
+    // Simulate a Variance Gamma path by sampling gamma time increments G ~ Γ(Δt/ν, ν)
+    fn simulate_variance_gamma_path(&self, rng: &mut impl Rng, variance_rate: f64, drift: f64, visit: &mut impl FnMut(f64)) {
+        let dt = self.maturity / self.steps as f64;
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+        let gamma_dist = Gamma::new(dt / variance_rate, variance_rate).unwrap();
+
+        // Martingale correction: ω = ln(1 - θν - σ²ν/2) / ν
+        let omega = f64::ln(1.0 - drift * variance_rate - 0.5 * self.volatility.powi(2) * variance_rate) / variance_rate;
+
+        let mut log_price = self.underlying.ln();
+        visit(self.underlying);
+
+        for _ in 0..self.steps {
+            let g: f64 = gamma_dist.sample(rng);
+            let z: f64 = normal_dist.sample(rng);
+            log_price += (self.carry() + omega) * dt + drift * g + self.volatility * g.sqrt() * z;
+            visit(log_price.exp());
+        }
+    }
+
+    // Simulate a NIG path by sampling inverse Gaussian time increments V ~ IG(δΔt/γ, (δΔt)²)
+    fn simulate_nig_path(&self, rng: &mut impl Rng, alpha: f64, beta: f64, scale: f64, visit: &mut impl FnMut(f64)) {
+        let dt = self.maturity / self.steps as f64;
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+        let gamma = f64::sqrt(alpha.powi(2) - beta.powi(2));
+        let inverse_gaussian_dist = InverseGaussian::new(scale * dt / gamma, (scale * dt).powi(2)).unwrap();
+
+        // Martingale correction: ω = δ(√(α² - (β + 1)²) - γ)
+        let omega = scale * (f64::sqrt(alpha.powi(2) - (beta + 1.0).powi(2)) - gamma);
+
+        let mut log_price = self.underlying.ln();
+        visit(self.underlying);
+
+        for _ in 0..self.steps {
+            let v: f64 = inverse_gaussian_dist.sample(rng);
+            let z: f64 = normal_dist.sample(rng);
+            log_price += (self.carry() + omega) * dt + beta * v + v.sqrt() * z;
+            visit(log_price.exp());
+        }
+    }
+
+    // Price of a cliquet on the simulated paths. Reset i falls on the time step nearest i/resets
+    // of the way to maturity, so `steps` should be a multiple of `resets`.
+    pub fn cliquet_price(&self, cliquet: &Cliquet) -> f64 {
+        let mut rng = rand::thread_rng();
+        let reset_step = |i: u32| (i as f64 * self.steps as f64 / cliquet.resets as f64).round() as usize;
+
+        let mut total_payoff = 0.0;
+        for _ in 0..self.simulations {
+            let path = self.simulate_path(&mut rng);
+
+            let coupons: f64 = (1..=cliquet.resets)
+                .map(|i| {
+                    let (start, end) = (path[reset_step(i - 1)], path[reset_step(i)]);
+                    // A path absorbed at zero has lost everything
+                    let periodic_return = if start > 0.0 { end / start - 1.0 } else { -1.0 };
+                    f64::min(f64::max(periodic_return, cliquet.local_floor), cliquet.local_cap)
+                })
+                .sum();
+
+            total_payoff += cliquet.notional * f64::min(f64::max(coupons, cliquet.global_floor), cliquet.global_cap);
+        }
+
+        total_payoff / self.simulations as f64 * f64::exp(-self.risk_free_rate * self.maturity)
+    }
+
+    // Price of an autocallable on the underlying, with its autocall probabilities and expected life
+    pub fn autocallable_report(&self, product: &Autocallable) -> Result<AutocallableReport, ScheduleError> {
+        let mut rng = rand::thread_rng();
+        let observation_steps = product.observation_steps(self.maturity, self.steps)?;
+
+        let settlements: Vec<Settlement> = (0..self.simulations)
+            .map(|_| {
+                let performances: Vec<f64> = self.simulate_path(&mut rng).iter().map(|s| s / self.underlying).collect();
+                product.settle(&performances, &observation_steps, self.risk_free_rate)
+            })
+            .collect();
+
+        Ok(AutocallableReport::from_settlements(product, &settlements))
+    }
+
+    // Price of a range accrual on the underlying, with the coupons accrued on each path paid at
+    // maturity. Observation dates fall on the nearest time step and must not share one.
+    pub fn range_accrual_price(&self, range_accrual: &RangeAccrual) -> Result<f64, ScheduleError> {
+        self.range_accrual_price_with_rng(range_accrual, &mut rand::thread_rng())
+    }
+
+    pub(crate) fn range_accrual_price_with_rng(&self, range_accrual: &RangeAccrual, rng: &mut impl Rng) -> Result<f64, ScheduleError> {
+        let observation_steps = range_accrual.observation_steps(self.maturity, self.steps)?;
+
+        let total_payoff: f64 = (0..self.simulations)
+            .map(|_| range_accrual.accrued_coupons(&self.simulate_path(rng), &observation_steps))
+            .sum();
+
+        Ok(total_payoff / self.simulations as f64 * f64::exp(-self.risk_free_rate * self.maturity))
+    }
+
+    // Price of a European option with a Parisian or double barrier monitored at each time step,
+    // tracking on each path how long the current excursion outside the corridor has lasted
+    pub fn barrier_price(&self, barrier: &ParisianBarrier, option_type: OptionType) -> f64 {
+        self.barrier_price_with_rng(barrier, option_type, &mut rand::thread_rng())
+    }
+
+    pub(crate) fn barrier_price_with_rng(&self, barrier: &ParisianBarrier, option_type: OptionType, rng: &mut impl Rng) -> f64 {
+        let trigger_count = barrier.trigger_count(self.maturity / self.steps as f64);
+
+        let mut total_payoff = 0.0;
+        for _ in 0..self.simulations {
+            let path = self.simulate_path(rng);
+
+            // Steps spent outside the corridor since the underlying last left it
+            let mut occupation = 0;
+            let triggered = path[1..].iter().any(|&price| {
+                occupation = if barrier.is_outside(price) { occupation + 1 } else { 0 };
+                occupation >= trigger_count
+            });
+
+            if triggered == (barrier.knock == BarrierKnock::In) {
+                total_payoff += payoff_value(self.payoff, self.strike, *path.last().unwrap(), option_type);
+            }
+        }
+
+        total_payoff / self.simulations as f64 * f64::exp(-self.risk_free_rate * self.maturity)
+    }
+
+    // Realised variance of a path, annualised over the maturity. A path absorbed at zero has
+    // infinite realised variance, as its log return at absorption is infinite.
+    fn realized_variance(&self, path: &[f64]) -> f64 {
+        if path.iter().any(|&price| price <= 0.0) {
+            return f64::INFINITY;
+        }
+        path.windows(2).map(|pair| f64::ln(pair[1] / pair[0]).powi(2)).sum::<f64>() / self.maturity
+    }
+
+    // Price of a payoff on the realised variance of the simulated paths
+    pub fn realized_variance_price(&self, payoff: &RealizedVariancePayoff) -> f64 {
+        let mut rng = rand::thread_rng();
+
+        let mut total_payoff = 0.0;
+        for _ in 0..self.simulations {
+            let variance = self.realized_variance(&self.simulate_path(&mut rng));
+            total_payoff += match *payoff {
+                RealizedVariancePayoff::VarianceSwap { strike, notional } => notional * (variance - strike),
+                RealizedVariancePayoff::VolatilitySwap { strike, notional } => notional * (variance.sqrt() - strike),
+            };
+        }
+
+        total_payoff / self.simulations as f64 * f64::exp(-self.risk_free_rate * self.maturity)
+    }
+
+    // Mean and variance of the realised variance, the fair variance strike and the input to the
+    // volatility swap convexity adjustment. Paths absorbed at zero (CEV, jumps) are excluded and
+    // counted in `absorbed_paths`.
+    pub fn realized_variance_moments(&self) -> Result<RealizedVarianceMoments, InsufficientPathsError> {
+        let mut rng = rand::thread_rng();
+        let variances: Vec<f64> = (0..self.simulations)
+            .map(|_| self.realized_variance(&self.simulate_path(&mut rng)))
+            .filter(|variance| variance.is_finite())
+            .collect();
+
+        let usable_paths = variances.len() as u32;
+        let absorbed_paths = self.simulations - usable_paths;
+        if usable_paths < 2 {
+            return Err(InsufficientPathsError { usable_paths, absorbed_paths });
+        }
+
+        let mean = variances.iter().sum::<f64>() / usable_paths as f64;
+        let variance = variances.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (usable_paths - 1) as f64;
+        Ok(RealizedVarianceMoments { mean, variance, absorbed_paths })
     }
 
     // Function to calculate the option payoff for a call option
     fn call_payoff(&self, final_price: f64) -> f64 {
-        f64::max(0.0, final_price - self.strike)
+        payoff_value(self.payoff, self.strike, final_price, OptionType::Call)
     }
 
     // Function to calculate the option payoff for a put option
     fn put_payoff(&self, final_price: f64) -> f64 {
-        f64::max(0.0, self.strike - final_price)
+        payoff_value(self.payoff, self.strike, final_price, OptionType::Put)
     }
 
     // Function to calculate the discounted average payoff across multiple simulations
@@ -55,7 +505,7 @@ impl MonteCarloModel {
         let mut total_payoff = 0.0;
 
         for _ in 0..self.simulations {
-            let final_price = self.simulate_path(rng);
+            let final_price = self.simulate_final_price(rng);
             total_payoff += payoff_func(final_price);
         }
 
@@ -151,7 +601,10 @@ impl OptionPricingModel for MonteCarloModel {
 
         let price = self.price(option_type.clone());
         let mut model_up = self.clone();
-        model_up.volatility += epsilon;
+        match &mut model_up.dynamics {
+            Dynamics::Heston { initial_variance, .. } => *initial_variance = (initial_variance.sqrt() + epsilon).powi(2),
+            _ => model_up.volatility += epsilon,
+        }
         let price_up = model_up.calculate_price(|final_price| {
             if option_type == OptionType::Call {
                 model_up.call_payoff(final_price)
@@ -177,3 +630,300 @@ impl OptionPricingModel for MonteCarloModel {
         self.cap_value(rho, -100.0, 100.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlackScholesModel, HestonModel, CevModel, VarianceGammaModel, NormalInverseGaussianModel};
+    use crate::{PowerOptionModel, GapOptionModel, QuantoModel, FxSettlement, RangeAccrualModel};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn monte_carlo_model(strike: f64, payoff: Payoff) -> MonteCarloModel {
+        MonteCarloModel {
+            underlying: 100.0,
+            strike,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+            simulations: 200_000,
+            steps: 1,
+            dynamics: Dynamics::GeometricBrownian,
+            payoff,
+        }
+    }
+
+    fn seeded_price(model: &MonteCarloModel, option_type: OptionType) -> f64 {
+        let mut rng = StdRng::seed_from_u64(42);
+        model.calculate_price(|final_price| payoff_value(model.payoff, model.strike, final_price, option_type), &mut rng)
+    }
+
+    #[test]
+    fn new_prices_vanilla_options_on_geometric_brownian_motion() {
+        let model = MonteCarloModel::new(100.0, 100.0, 1.0, 0.2, 0.05, 200_000, 10);
+        let black_scholes = BlackScholesModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+        };
+        for option_type in [OptionType::Call, OptionType::Put] {
+            assert!((seeded_price(&model, option_type) - black_scholes.price(option_type)).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn dividend_yield_matches_black_scholes_on_the_dividend_adjusted_spot() {
+        let model = MonteCarloModel { dividend_yield: 0.03, ..monte_carlo_model(100.0, Payoff::Vanilla) };
+        let black_scholes = BlackScholesModel {
+            underlying: 100.0 * f64::exp(-0.03),
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+        };
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (seeded_price(&model, option_type), black_scholes.price(option_type));
+            assert!((price - expected).abs() < 0.1, "{:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+
+    #[test]
+    fn heston_paths_match_fourier_prices() {
+        // Including κ = 0, where the variance is a martingale
+        for mean_reversion in [2.0, 0.0] {
+            let heston = HestonModel {
+                underlying: 100.0,
+                strike: 100.0,
+                maturity: 1.0,
+                risk_free_rate: 0.05,
+                initial_variance: 0.04,
+                mean_reversion,
+                long_run_variance: 0.04,
+                vol_of_vol: 0.5,
+                correlation: -0.7,
+            };
+            let model = MonteCarloModel {
+                simulations: 50_000,
+                steps: 50,
+                dynamics: Dynamics::Heston { initial_variance: 0.04, mean_reversion, long_run_variance: 0.04, vol_of_vol: 0.5, correlation: -0.7 },
+                ..monte_carlo_model(100.0, Payoff::Vanilla)
+            };
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (price, expected) = (seeded_price(&model, option_type), heston.price(option_type));
+                assert!((price - expected).abs() < 0.15, "κ = {} {:?}: {} vs {}", mean_reversion, option_type, price, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn cev_paths_match_noncentral_chi_squared_prices() {
+        // Local volatility of 20% at the spot on either side of α = 1
+        for elasticity in [0.5, 0.9, 1.3] {
+            let volatility = 0.2 * f64::powf(100.0, 1.0 - elasticity);
+            let cev = CevModel {
+                underlying: 100.0,
+                strike: 105.0,
+                maturity: 1.0,
+                volatility,
+                risk_free_rate: 0.05,
+                elasticity,
+            };
+            let model = MonteCarloModel {
+                strike: 105.0,
+                volatility,
+                simulations: 50_000,
+                steps: 100,
+                dynamics: Dynamics::Cev { elasticity },
+                ..monte_carlo_model(105.0, Payoff::Vanilla)
+            };
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (price, expected) = (seeded_price(&model, option_type), cev.price(option_type));
+                assert!((price - expected).abs() < 0.15, "α = {} {:?}: {} vs {}", elasticity, option_type, price, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn subordinated_paths_match_fourier_prices() {
+        let variance_gamma = VarianceGammaModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            risk_free_rate: 0.05,
+            volatility: 0.2,
+            variance_rate: 0.2,
+            drift: -0.14,
+        };
+        let normal_inverse_gaussian = NormalInverseGaussianModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            risk_free_rate: 0.05,
+            alpha: 15.0,
+            beta: -5.0,
+            scale: 0.5,
+        };
+        // Increments of both processes are sampled exactly, so a single step is enough
+        let variance_gamma_monte_carlo = MonteCarloModel {
+            dynamics: Dynamics::VarianceGamma { variance_rate: 0.2, drift: -0.14 },
+            ..monte_carlo_model(100.0, Payoff::Vanilla)
+        };
+        let normal_inverse_gaussian_monte_carlo = MonteCarloModel {
+            dynamics: Dynamics::NormalInverseGaussian { alpha: 15.0, beta: -5.0, scale: 0.5 },
+            ..monte_carlo_model(100.0, Payoff::Vanilla)
+        };
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (seeded_price(&variance_gamma_monte_carlo, option_type), variance_gamma.price(option_type));
+            assert!((price - expected).abs() < 0.1, "VG {:?}: {} vs {}", option_type, price, expected);
+
+            let (price, expected) = (seeded_price(&normal_inverse_gaussian_monte_carlo, option_type), normal_inverse_gaussian.price(option_type));
+            assert!((price - expected).abs() < 0.1, "NIG {:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+
+    #[test]
+    fn absorbed_paths_have_infinite_realized_variance() {
+        let model = MonteCarloModel {
+            simulations: 2_000,
+            steps: 50,
+            dynamics: Dynamics::Cev { elasticity: 0.0 },
+            volatility: 60.0,
+            ..monte_carlo_model(100.0, Payoff::Vanilla)
+        };
+        assert_eq!(model.realized_variance(&[100.0, 40.0, 0.0, 0.0]), f64::INFINITY);
+
+        let payoff = RealizedVariancePayoff::VarianceSwap { strike: 0.04, notional: 1.0 };
+        assert_eq!(model.realized_variance_price(&payoff), f64::INFINITY);
+
+        // Moments come from the surviving paths only
+        let moments = model.realized_variance_moments().unwrap();
+        assert!(moments.absorbed_paths > 0 && moments.absorbed_paths < model.simulations);
+        assert!(moments.mean.is_finite() && moments.variance.is_finite());
+    }
+
+    #[test]
+    fn realized_variance_moments_need_two_paths() {
+        for simulations in [0, 1] {
+            let model = MonteCarloModel { simulations, steps: 10, ..monte_carlo_model(100.0, Payoff::Vanilla) };
+            let error = InsufficientPathsError { usable_paths: simulations, absorbed_paths: 0 };
+            assert_eq!(model.realized_variance_moments(), Err(error));
+        }
+
+        let model = MonteCarloModel { simulations: 2, steps: 10, ..monte_carlo_model(100.0, Payoff::Vanilla) };
+        assert_eq!(model.realized_variance_moments().unwrap().absorbed_paths, 0);
+    }
+
+    #[test]
+    fn quanto_paths_match_quanto_model() {
+        for fx_correlation in [0.0, -0.5] {
+            let quanto = QuantoModel {
+                underlying: 100.0,
+                strike: 100.0,
+                maturity: 1.0,
+                volatility: 0.2,
+                risk_free_rate: 0.05,
+                foreign_rate: 0.02,
+                dividend_yield: 0.01,
+                fx_volatility: 0.1,
+                fx_correlation,
+                settlement: FxSettlement::Quanto { fixed_rate: 1.0 },
+            };
+            let model = MonteCarloModel {
+                dynamics: Dynamics::Quanto { foreign_rate: 0.02, dividend_yield: 0.01, fx_volatility: 0.1, fx_correlation },
+                ..monte_carlo_model(100.0, Payoff::Vanilla)
+            };
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (price, expected) = (seeded_price(&model, option_type), quanto.price(option_type));
+                assert!((price - expected).abs() < 0.1, "ρ = {} {:?}: {} vs {}", fx_correlation, option_type, price, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn range_accrual_matches_digital_call_spread_replication() {
+        let range_accrual = RangeAccrual {
+            observation_times: (1..=12).map(|month| month as f64 / 12.0).collect(),
+            lower_barrier: 90.0,
+            upper_barrier: 115.0,
+            coupon: 0.01,
+            notional: 100.0,
+        };
+        let replication = RangeAccrualModel {
+            underlying: 100.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+            spread_width: 0.01,
+        };
+        let model = MonteCarloModel { simulations: 50_000, steps: 12, ..monte_carlo_model(100.0, Payoff::Vanilla) };
+        let price = model.range_accrual_price_with_rng(&range_accrual, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        // Standard error from the same paths
+        let mut rng = StdRng::seed_from_u64(42);
+        let observation_steps = range_accrual.observation_steps(model.maturity, model.steps).unwrap();
+        let payoffs: Vec<f64> = (0..model.simulations)
+            .map(|_| range_accrual.accrued_coupons(&model.simulate_path(&mut rng), &observation_steps) * f64::exp(-0.05))
+            .collect();
+        let mean = payoffs.iter().sum::<f64>() / payoffs.len() as f64;
+        let standard_error = f64::sqrt(payoffs.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (payoffs.len() - 1) as f64)
+            / (payoffs.len() as f64).sqrt();
+
+        let expected = replication.range_accrual_price(&range_accrual);
+        assert!((price - expected).abs() < 3.0 * standard_error, "{} vs {} ± {}", price, expected, standard_error);
+    }
+
+    #[test]
+    fn range_accrual_schedules_are_validated() {
+        let range_accrual = RangeAccrual {
+            observation_times: vec![0.5, 1.5],
+            lower_barrier: 90.0,
+            upper_barrier: 115.0,
+            coupon: 0.01,
+            notional: 100.0,
+        };
+        let model = MonteCarloModel { simulations: 10, steps: 12, ..monte_carlo_model(100.0, Payoff::Vanilla) };
+        assert_eq!(model.range_accrual_price(&range_accrual), Err(ScheduleError::AfterMaturity { time: 1.5, maturity: 1.0 }));
+
+        // Daily dates cannot be resolved by monthly steps
+        let daily = RangeAccrual { observation_times: (1..=252).map(|day| day as f64 / 252.0).collect(), ..range_accrual };
+        assert_eq!(model.range_accrual_price(&daily), Err(ScheduleError::SameStep { index: 0 }));
+    }
+
+    #[test]
+    fn power_and_gap_payoffs_match_closed_forms() {
+        let power = PowerOptionModel {
+            underlying: 100.0,
+            strike: 10_000.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+            exponent: 2.0,
+        };
+        let gap = GapOptionModel {
+            underlying: 100.0,
+            strike: 100.0,
+            trigger_strike: 105.0,
+            maturity: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+        };
+        let power_monte_carlo = monte_carlo_model(10_000.0, Payoff::Power { exponent: 2.0 });
+        let gap_monte_carlo = monte_carlo_model(100.0, Payoff::Gap { trigger_strike: 105.0 });
+
+        // About three standard errors of the Monte Carlo prices
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (seeded_price(&power_monte_carlo, option_type), power.price(option_type));
+            assert!((price - expected).abs() < 0.01 * expected.max(100.0), "power {:?}: {} vs {}", option_type, price, expected);
+
+            let (price, expected) = (seeded_price(&gap_monte_carlo, option_type), gap.price(option_type));
+            assert!((price - expected).abs() < 0.1, "gap {:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use crate::{OptionPricingModel, OptionType};
+use crate::pricing_models::lattice::option_payoff;
+use crate::pricing_models::autocallable::{Autocallable, AutocallableReport, Settlement, ScheduleError};
+use crate::linear_algebra::{cholesky, nearest_correlation};
+use rand_distr::{Normal, Distribution};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use std::fmt;
+
+// Combination of the terminal asset prices compared against the strike
+#[derive(PartialEq, Debug, Clone)]
+pub enum MultiAssetPayoff {
+    // Weighted sum Σ w_i S_i
+    Basket { weights: Vec<f64> },
+    // Highest terminal price, max_i S_i (call on max / put on max)
+    BestOf,
+    // Lowest terminal price, min_i S_i (call on min / put on min)
+    WorstOf,
+    // Rainbow: weights applied to the terminal prices ranked from highest to lowest
+    Rainbow { weights: Vec<f64> },
+}
+
+// Per-asset input whose length does not match the number of underlyings
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct DimensionError {
+    pub input: &'static str,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} has {} entries for {} underlyings", self.input, self.found, self.expected)
+    }
+}
+
+impl std::error::Error for DimensionError {}
+
+// Correlation matrix that is not symmetric with a unit diagonal and entries in [-1, 1]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CorrelationError {
+    Asymmetric { row: usize, column: usize },
+    DiagonalNotOne { index: usize, value: f64 },
+    OutOfRange { row: usize, column: usize, value: f64 },
+}
+
+impl fmt::Display for CorrelationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CorrelationError::Asymmetric { row, column } => {
+                write!(f, "correlation entries ({}, {}) and ({}, {}) differ", row, column, column, row)
+            }
+            CorrelationError::DiagonalNotOne { index, value } => {
+                write!(f, "correlation diagonal entry {} is {}, not 1", index, value)
+            }
+            CorrelationError::OutOfRange { row, column, value } => {
+                write!(f, "correlation entry ({}, {}) is {}, outside [-1, 1]", row, column, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CorrelationError {}
+
+// Multi-asset inputs that cannot be priced
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MultiAssetError {
+    Dimension(DimensionError),
+    Correlation(CorrelationError),
+}
+
+impl fmt::Display for MultiAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MultiAssetError::Dimension(error) => error.fmt(f),
+            MultiAssetError::Correlation(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for MultiAssetError {}
+
+impl From<DimensionError> for MultiAssetError {
+    fn from(error: DimensionError) -> Self {
+        MultiAssetError::Dimension(error)
+    }
+}
+
+impl From<CorrelationError> for MultiAssetError {
+    fn from(error: CorrelationError) -> Self {
+        MultiAssetError::Correlation(error)
+    }
+}
+
+// Checks that a square correlation matrix is symmetric with a unit diagonal and entries in
+// [-1, 1], up to rounding
+pub(crate) fn check_correlation(correlation: &[Vec<f64>]) -> Result<(), CorrelationError> {
+    const TOLERANCE: f64 = 1e-10;
+
+    for (row, entries) in correlation.iter().enumerate() {
+        if (entries[row] - 1.0).abs() > TOLERANCE {
+            return Err(CorrelationError::DiagonalNotOne { index: row, value: entries[row] });
+        }
+        for (column, &value) in entries.iter().enumerate().take(row) {
+            if (value - correlation[column][row]).abs() > TOLERANCE {
+                return Err(CorrelationError::Asymmetric { row, column });
+            }
+            if value.abs() > 1.0 {
+                return Err(CorrelationError::OutOfRange { row, column, value });
+            }
+        }
+    }
+    Ok(())
+}
+
+// Checks that each named input has one entry per asset, including every row of the correlation matrix
+pub(crate) fn check_dimensions(
+    assets: usize,
+    inputs: &[(&'static str, usize)],
+    correlation: &[Vec<f64>],
+) -> Result<(), DimensionError> {
+    let rows = correlation.iter().map(|row| ("correlation row", row.len()));
+    for (input, found) in inputs.iter().cloned().chain([("correlation", correlation.len())]).chain(rows) {
+        if found != assets {
+            return Err(DimensionError { input, expected: assets, found });
+        }
+    }
+    Ok(())
+}
+
+// Monte Carlo for European options on several correlated GBM assets with continuous dividend
+// yields. Correlated normals come from the Cholesky factor of the correlation matrix.
+#[derive(Clone)]
+pub struct MultiAssetMonteCarloModel {
+    pub underlyings: Vec<f64>,
+    pub volatilities: Vec<f64>,
+    pub dividend_yields: Vec<f64>,
+    pub correlation: Vec<Vec<f64>>,
+    pub strike: f64,
+    pub maturity: f64,
+    pub risk_free_rate: f64,
+    pub simulations: u32,
+    pub payoff: MultiAssetPayoff,
+    pub seed: u64, // Paths are regenerated from this seed, so bumped greeks share random numbers
+}
+
+impl MultiAssetMonteCarloModel {
+    // Checks that the volatilities, dividend yields, correlation matrix and payoff weights all
+    // have one entry per underlying and that the correlation matrix is a valid correlation matrix
+    pub fn validate(&self) -> Result<(), MultiAssetError> {
+        let mut inputs = vec![("volatilities", self.volatilities.len()), ("dividend_yields", self.dividend_yields.len())];
+        if let MultiAssetPayoff::Basket { weights } | MultiAssetPayoff::Rainbow { weights } = &self.payoff {
+            inputs.push(("weights", weights.len()));
+        }
+        check_dimensions(self.underlyings.len(), &inputs, &self.correlation)?;
+        Ok(check_correlation(&self.correlation)?)
+    }
+
+    // Correlation matrix used for simulation: the input if it is positive definite, otherwise
+    // the repaired matrix with clipped eigenvalues. Only valid inputs (see `validate`) reach here.
+    pub fn correlation_matrix(&self) -> Vec<Vec<f64>> {
+        if cholesky(&self.correlation).is_some() {
+            self.correlation.clone()
+        } else {
+            nearest_correlation(&self.correlation)
+        }
+    }
+
+    // Cholesky factor of the simulation correlation matrix. Panics if the inputs fail `validate`.
+    fn correlation_factor(&self) -> Vec<Vec<f64>> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+        cholesky(&self.correlation_matrix()).expect("repaired correlation matrix is positive definite")
+    }
+
+    fn combined_price(&self, prices: &mut [f64]) -> f64 {
+        match &self.payoff {
+            MultiAssetPayoff::Basket { weights } => weights.iter().zip(prices.iter()).map(|(w, s)| w * s).sum(),
+            MultiAssetPayoff::BestOf => prices.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            MultiAssetPayoff::WorstOf => prices.iter().cloned().fold(f64::INFINITY, f64::min),
+            MultiAssetPayoff::Rainbow { weights } => {
+                prices.sort_by(|a, b| b.total_cmp(a));
+                weights.iter().zip(prices.iter()).map(|(w, s)| w * s).sum()
+            }
+        }
+    }
+
+    // Sample the terminal prices of all assets exactly
+    fn simulate_terminal_prices(&self, factor: &[Vec<f64>], rng: &mut impl Rng, prices: &mut [f64]) {
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+        let t = self.maturity;
+        let z: Vec<f64> = (0..self.underlyings.len()).map(|_| normal_dist.sample(rng)).collect();
+
+        for (i, price) in prices.iter_mut().enumerate() {
+            // Correlated normal: W_i = Σ_j L_ij Z_j
+            let w: f64 = factor[i].iter().zip(&z).map(|(l, z)| l * z).sum();
+            let sigma = self.volatilities[i];
+            *price = self.underlyings[i]
+                * f64::exp((self.risk_free_rate - self.dividend_yields[i] - 0.5 * sigma.powi(2)) * t
+                    + sigma * f64::sqrt(t) * w);
+        }
+    }
+
+    // Worst performance min_i S_i(t)/S_i(0) today and after each of `steps` equal time steps
+    fn simulate_worst_performance(&self, factor: &[Vec<f64>], rng: &mut impl Rng, steps: u32) -> Vec<f64> {
+        let normal_dist = Normal::new(0.0, 1.0).unwrap();
+        let dt = self.maturity / steps as f64;
+        let mut log_performances = vec![0.0; self.underlyings.len()];
+        let mut path = vec![1.0];
+
+        for _ in 0..steps {
+            let z: Vec<f64> = (0..self.underlyings.len()).map(|_| normal_dist.sample(rng)).collect();
+            for (i, log_performance) in log_performances.iter_mut().enumerate() {
+                let w: f64 = factor[i].iter().zip(&z).map(|(l, z)| l * z).sum();
+                let sigma = self.volatilities[i];
+                *log_performance += (self.risk_free_rate - self.dividend_yields[i] - 0.5 * sigma.powi(2)) * dt
+                    + sigma * f64::sqrt(dt) * w;
+            }
+            path.push(log_performances.iter().cloned().fold(f64::INFINITY, f64::min).exp());
+        }
+
+        path
+    }
+
+    // Price of a worst-of autocallable on all the underlyings, simulated with `steps` time steps
+    // to maturity, with its autocall probabilities and expected life. The `strike` and `payoff`
+    // of the model are not used.
+    pub fn autocallable_report(&self, product: &Autocallable, steps: u32) -> Result<AutocallableReport, ScheduleError> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let factor = self.correlation_factor();
+        let observation_steps = product.observation_steps(self.maturity, steps)?;
+
+        let settlements: Vec<Settlement> = (0..self.simulations)
+            .map(|_| {
+                let performances = self.simulate_worst_performance(&factor, &mut rng, steps);
+                product.settle(&performances, &observation_steps, self.risk_free_rate)
+            })
+            .collect();
+
+        Ok(AutocallableReport::from_settlements(product, &settlements))
+    }
+
+    // Delta to the i-th underlying
+    pub fn asset_delta(&self, asset: usize, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlyings[asset] += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlyings[asset] -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    // Vega to the i-th volatility
+    pub fn asset_vega(&self, asset: usize, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatilities[asset] += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+// Greeks from the trait bump every underlying or volatility together
+impl OptionPricingModel for MultiAssetMonteCarloModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let factor = self.correlation_factor();
+        let mut prices = vec![0.0; self.underlyings.len()];
+
+        let mut total_payoff = 0.0;
+        for _ in 0..self.simulations {
+            self.simulate_terminal_prices(&factor, &mut rng, &mut prices);
+            total_payoff += option_payoff(self.strike, self.combined_price(&mut prices), option_type);
+        }
+
+        total_payoff / self.simulations as f64 * f64::exp(-self.risk_free_rate * self.maturity)
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlyings.iter_mut().for_each(|s| *s += epsilon);
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlyings.iter_mut().for_each(|s| *s -= epsilon);
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlyings.iter_mut().for_each(|s| *s += epsilon);
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlyings.iter_mut().for_each(|s| *s -= epsilon);
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatilities.iter_mut().for_each(|sigma| *sigma += epsilon);
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
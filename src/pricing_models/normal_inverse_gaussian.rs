@@ -0,0 +1,98 @@
+use crate::{OptionPricingModel, OptionType, CharacteristicFunction};
+use crate::pricing_models::fourier::{gil_pelaez_price, exercise_probabilities};
+use num_complex::Complex64;
+
+// Normal Inverse Gaussian: Brownian motion with drift β run on an inverse Gaussian clock
+#[derive(Clone)]
+pub struct NormalInverseGaussianModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub risk_free_rate: f64,
+    pub alpha: f64, // α: Tail heaviness, α > |β| and α > |β + 1|
+    pub beta: f64,  // β: Asymmetry, negative values skew to the left
+    pub scale: f64, // δ: Scale of the distribution
+}
+
+impl NormalInverseGaussianModel {
+    // Martingale correction: ω = δ(√(α² - (β + 1)²) - √(α² - β²))
+    fn martingale_correction(&self) -> f64 {
+        self.scale
+            * (f64::sqrt(self.alpha.powi(2) - (self.beta + 1.0).powi(2))
+                - f64::sqrt(self.alpha.powi(2) - self.beta.powi(2)))
+    }
+}
+
+impl CharacteristicFunction for NormalInverseGaussianModel {
+    // φ(u) = exp(iu(ln S + (r + ω)T) + δT(√(α² - β²) - √(α² - (β + iu)²)))
+    fn characteristic_function(&self, u: Complex64) -> Complex64 {
+        let i = Complex64::i();
+        let drift = self.underlying.ln() + (self.risk_free_rate + self.martingale_correction()) * self.maturity;
+        let gamma = f64::sqrt(self.alpha.powi(2) - self.beta.powi(2));
+        let shifted = (self.alpha.powi(2) - (self.beta + i * u).powi(2)).sqrt();
+
+        (i * u * drift + self.scale * self.maturity * (gamma - shifted)).exp()
+    }
+}
+
+impl OptionPricingModel for NormalInverseGaussianModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        gil_pelaez_price(self, self.strike, self.maturity, self.risk_free_rate, option_type)
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        // Exponential Lévy prices are homogeneous in (S, K), so delta is the share-measure probability
+        let (p1, _) = exercise_probabilities(self, self.strike);
+        match option_type {
+            OptionType::Call => p1,
+            OptionType::Put => p1 - 1.0,
+        }
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let delta_up = model_up.delta(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let delta_down = model_down.delta(option_type);
+
+        (delta_up - delta_down) / (2.0 * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        // NIG has no diffusion volatility; report the sensitivity to the scale δ
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.scale += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{OptionPricingModel, OptionType};
+use crate::distributions::normal_cdf;
+
+// Asymmetric power option paying max(S^n - K, 0) for a call and max(K - S^n, 0) for a put. S_T^n
+// is lognormal with volatility |n|σ, so Black's formula applies to its forward.
+#[derive(Clone)]
+pub struct PowerOptionModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+    pub exponent: f64, // n: Power applied to the asset price
+}
+
+impl PowerOptionModel {
+    // E[S_T^n] = S^n e^((n(r - q - σ²/2) + n²σ²/2)T)
+    fn forward(&self) -> f64 {
+        let (n, sigma) = (self.exponent, self.volatility);
+        self.underlying.powf(n)
+            * f64::exp((n * (self.risk_free_rate - self.dividend_yield - 0.5 * sigma.powi(2)) + 0.5 * (n * sigma).powi(2))
+                * self.maturity)
+    }
+}
+
+impl OptionPricingModel for PowerOptionModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let forward = self.forward();
+        let sigma_sqrt_t = (self.exponent * self.volatility).abs() * f64::sqrt(self.maturity);
+        let d1 = f64::ln(forward / self.strike) / sigma_sqrt_t + 0.5 * sigma_sqrt_t;
+        let d2 = d1 - sigma_sqrt_t;
+        let discount = f64::exp(-self.risk_free_rate * self.maturity);
+
+        match option_type {
+            OptionType::Call => discount * (forward * normal_cdf(d1) - self.strike * normal_cdf(d2)),
+            OptionType::Put => discount * (self.strike * normal_cdf(-d2) - forward * normal_cdf(-d1)),
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+
+// How a payoff on a foreign asset is converted into the domestic currency
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum FxSettlement {
+    // Quanto: X̄ max(S_T - K, 0) at a fixed exchange rate, strike in foreign currency
+    Quanto { fixed_rate: f64 },
+    // Composite: max(X_T S_T - K, 0), strike in domestic currency, X the spot exchange rate
+    // (domestic per foreign)
+    Composite { spot_rate: f64 },
+}
+
+// European option on a foreign asset paid in domestic currency. `risk_free_rate` is the domestic
+// rate; the asset and the exchange rate are correlated GBMs.
+#[derive(Clone)]
+pub struct QuantoModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub foreign_rate: f64,
+    pub dividend_yield: f64,
+    pub fx_volatility: f64,
+    pub fx_correlation: f64, // ρ: Correlation between the asset and the exchange rate
+    pub settlement: FxSettlement,
+}
+
+impl QuantoModel {
+    // Domestic-measure drift of a quanto asset: r_f - q - ρσσ_X
+    pub fn quanto_drift(&self) -> f64 {
+        self.foreign_rate - self.dividend_yield - self.fx_correlation * self.volatility * self.fx_volatility
+    }
+
+    // Equivalent Black-Scholes model, the factor mapping the foreign spot to its underlying, and
+    // the scale of the payoff
+    fn equivalent_model(&self) -> (BlackScholesModel, f64, f64) {
+        let t = self.maturity;
+        match self.settlement {
+            FxSettlement::Quanto { fixed_rate } => {
+                // Asset with carry b = r_f - q - ρσσ_X: Black-Scholes on S e^((b - r)T)
+                let factor = f64::exp((self.quanto_drift() - self.risk_free_rate) * t);
+                let model = BlackScholesModel {
+                    underlying: self.underlying * factor,
+                    strike: self.strike,
+                    maturity: t,
+                    volatility: self.volatility,
+                    risk_free_rate: self.risk_free_rate,
+                };
+                (model, factor, fixed_rate)
+            }
+            FxSettlement::Composite { spot_rate } => {
+                // X S is a domestic asset with yield q and volatility √(σ² + σ_X² + 2ρσσ_X)
+                let factor = spot_rate * f64::exp(-self.dividend_yield * t);
+                let volatility = f64::sqrt(
+                    self.volatility.powi(2) + self.fx_volatility.powi(2)
+                        + 2.0 * self.fx_correlation * self.volatility * self.fx_volatility,
+                );
+                let model = BlackScholesModel {
+                    underlying: self.underlying * factor,
+                    strike: self.strike,
+                    maturity: t,
+                    volatility,
+                    risk_free_rate: self.risk_free_rate,
+                };
+                (model, factor, 1.0)
+            }
+        }
+    }
+}
+
+// Greeks are with respect to the foreign asset price and its volatility
+impl OptionPricingModel for QuantoModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let (model, _, scale) = self.equivalent_model();
+        scale * model.price(option_type)
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let (model, factor, scale) = self.equivalent_model();
+        scale * factor * model.delta(option_type)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let (model, factor, scale) = self.equivalent_model();
+        scale * factor.powi(2) * model.gamma(option_type)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quanto_model(fx_correlation: f64, settlement: FxSettlement) -> QuantoModel {
+        QuantoModel {
+            underlying: 100.0,
+            strike: 95.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+            foreign_rate: 0.02,
+            dividend_yield: 0.01,
+            fx_volatility: 0.1,
+            fx_correlation,
+            settlement,
+        }
+    }
+
+    #[test]
+    fn uncorrelated_quanto_matches_black_scholes_on_the_foreign_forward() {
+        let model = quanto_model(0.0, FxSettlement::Quanto { fixed_rate: 1.5 });
+        // Without correlation the asset drifts at r_f - q, so the domestic forward is S e^((r_f - q)T)
+        let black_scholes = BlackScholesModel {
+            underlying: 100.0 * f64::exp(0.02 - 0.01 - 0.05),
+            strike: 95.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+        };
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (model.price(option_type), 1.5 * black_scholes.price(option_type));
+            assert!((price - expected).abs() < 1e-10, "{:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+
+    #[test]
+    fn correlation_shifts_the_quanto_drift() {
+        let uncorrelated = quanto_model(0.0, FxSettlement::Quanto { fixed_rate: 1.0 });
+        let correlated = quanto_model(0.5, FxSettlement::Quanto { fixed_rate: 1.0 });
+        assert!((correlated.quanto_drift() - (uncorrelated.quanto_drift() - 0.5 * 0.25 * 0.1)).abs() < 1e-15);
+        // A lower drift makes the call cheaper and the put dearer
+        assert!(correlated.price(OptionType::Call) < uncorrelated.price(OptionType::Call));
+        assert!(correlated.price(OptionType::Put) > uncorrelated.price(OptionType::Put));
+    }
+
+    #[test]
+    fn composite_without_fx_volatility_matches_black_scholes_in_domestic_currency() {
+        let model = QuantoModel { fx_volatility: 0.0, ..quanto_model(0.3, FxSettlement::Composite { spot_rate: 1.2 }) };
+        let black_scholes = BlackScholesModel {
+            underlying: 1.2 * 100.0 * f64::exp(-0.01),
+            strike: 95.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+        };
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (model.price(option_type), black_scholes.price(option_type));
+            assert!((price - expected).abs() < 1e-10, "{:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+}
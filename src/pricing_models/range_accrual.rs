@@ -0,0 +1,91 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+use crate::pricing_models::autocallable::{observation_steps, ScheduleError};
+
+// Range accrual (corridor) note accruing notional × coupon for each observation date on which the
+// underlying is within [lower_barrier, upper_barrier], with the accrued coupons paid at the
+// model's maturity. Use a zero lower or infinite upper barrier for a one-sided range.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RangeAccrual {
+    pub observation_times: Vec<f64>, // Increasing observation dates in years, after today and up to the model's maturity
+    pub lower_barrier: f64,
+    pub upper_barrier: f64,
+    pub coupon: f64, // Coupon per observation inside the range as a fraction of the notional
+    pub notional: f64,
+}
+
+impl RangeAccrual {
+    // Observation dates as indices into a path with `steps` equal steps to `maturity`, checked as
+    // for autocallables
+    pub(crate) fn observation_steps(&self, maturity: f64, steps: u32) -> Result<Vec<usize>, ScheduleError> {
+        observation_steps(&self.observation_times, maturity, steps)
+    }
+
+    // Coupons accrued along a path of prices at each simulation step, starting with today
+    pub(crate) fn accrued_coupons(&self, path: &[f64], observation_steps: &[usize]) -> f64 {
+        let days_in_range = observation_steps
+            .iter()
+            .filter(|&&step| (self.lower_barrier..=self.upper_barrier).contains(&path[step]))
+            .count();
+        self.notional * self.coupon * days_in_range as f64
+    }
+}
+
+// Range accruals priced by static replication. The accrual on each observation date is a digital
+// call at the lower barrier less one at the upper barrier, and each digital is replicated by a
+// tight call spread (C(K - h) - C(K + h)) / 2h of Black-Scholes calls expiring on that date.
+#[derive(Clone)]
+pub struct RangeAccrualModel {
+    pub underlying: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+    pub spread_width: f64, // Half-width h of the call spreads, in units of the underlying
+}
+
+impl RangeAccrualModel {
+    // Black-Scholes call expiring at `time`, on the dividend-adjusted spot
+    fn call_price(&self, strike: f64, time: f64) -> f64 {
+        BlackScholesModel {
+            underlying: self.underlying * f64::exp(-self.dividend_yield * time),
+            strike,
+            maturity: time,
+            volatility: self.volatility,
+            risk_free_rate: self.risk_free_rate,
+        }.price(OptionType::Call)
+    }
+
+    // Cash-or-nothing digital call paying 1 at `time` if the underlying is above `strike`,
+    // replicated by a call spread centred on the strike
+    pub fn digital_call_spread(&self, strike: f64, time: f64) -> f64 {
+        if strike.is_infinite() {
+            return 0.0;
+        }
+        // Strikes within the spread of zero are certain to finish above it
+        if strike <= self.spread_width {
+            return f64::exp(-self.risk_free_rate * time);
+        }
+
+        let h = self.spread_width;
+        (self.call_price(strike - h, time) - self.call_price(strike + h, time)) / (2.0 * h)
+    }
+
+    // Probability under the risk-neutral measure that the underlying is inside the range at `time`
+    pub fn accrual_probability(&self, range_accrual: &RangeAccrual, time: f64) -> f64 {
+        (self.digital_call_spread(range_accrual.lower_barrier, time)
+            - self.digital_call_spread(range_accrual.upper_barrier, time))
+            * f64::exp(self.risk_free_rate * time)
+    }
+
+    // Price of the range accrual: the discounted coupon times the expected number of observation
+    // dates inside the range
+    pub fn range_accrual_price(&self, range_accrual: &RangeAccrual) -> f64 {
+        let expected_days_in_range: f64 = range_accrual.observation_times
+            .iter()
+            .map(|&t| self.accrual_probability(range_accrual, t))
+            .sum();
+
+        range_accrual.notional * range_accrual.coupon * expected_days_in_range
+            * f64::exp(-self.risk_free_rate * self.maturity)
+    }
+}
@@ -0,0 +1,336 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+
+// Asymptotic expansion used to turn SABR parameters into a Black implied volatility
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SabrVolatilityFormula {
+    // Hagan, Kumar, Lesniewski and Woodward (2002)
+    Hagan,
+    // Obloj (2008) leading-order correction, more accurate away from the money
+    Obloj,
+}
+
+#[derive(Clone)]
+pub struct SabrModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub risk_free_rate: f64,
+    pub alpha: f64,       // α: Initial level of the stochastic volatility
+    pub beta: f64,        // β: CEV exponent of the forward, in [0, 1]
+    pub correlation: f64, // ρ: Correlation between the forward and its volatility
+    pub vol_of_vol: f64,  // ν: Volatility of the volatility
+    pub formula: SabrVolatilityFormula,
+}
+
+impl SabrModel {
+    // Bump size for derivatives of the implied volatility
+    const BUMP: f64 = 1e-4;
+    // Nelder-Mead settings for calibration
+    const MAX_ITERATIONS: usize = 2000;
+    const TOLERANCE: f64 = 1e-14;
+
+    fn forward(&self) -> f64 {
+        self.underlying * f64::exp(self.risk_free_rate * self.maturity)
+    }
+
+    // x(z) = ln((√(1 - 2ρz + z²) + z - ρ) / (1 - ρ))
+    fn x_of_z(&self, z: f64) -> f64 {
+        let rho = self.correlation;
+        f64::ln((f64::sqrt(1.0 - 2.0 * rho * z + z * z) + z - rho) / (1.0 - rho))
+    }
+
+    // Black implied volatility for the given forward and strike
+    fn volatility_at(&self, forward: f64, strike: f64) -> f64 {
+        let (alpha, beta, rho, nu) = (self.alpha, self.beta, self.correlation, self.vol_of_vol);
+        let one_minus_beta = 1.0 - beta;
+        let log_moneyness = f64::ln(forward / strike);
+        let fk_mid = (forward * strike).powf(0.5 * one_minus_beta);
+
+        // Time correction: 1 + [(1-β)²α²/(24(FK)^(1-β)) + ρβνα/(4(FK)^((1-β)/2)) + (2-3ρ²)ν²/24] T
+        let correction = 1.0
+            + (one_minus_beta.powi(2) / 24.0 * alpha.powi(2) / fk_mid.powi(2)
+                + rho * beta * nu * alpha / (4.0 * fk_mid)
+                + (2.0 - 3.0 * rho.powi(2)) / 24.0 * nu.powi(2))
+                * self.maturity;
+
+        if log_moneyness.abs() < 1e-12 {
+            // At the money both expansions reduce to α / F^(1-β)
+            return alpha / forward.powf(one_minus_beta) * correction;
+        }
+
+        let leading = match self.formula {
+            SabrVolatilityFormula::Hagan => {
+                let z = nu / alpha * fk_mid * log_moneyness;
+                let denominator = fk_mid
+                    * (1.0
+                        + one_minus_beta.powi(2) / 24.0 * log_moneyness.powi(2)
+                        + one_minus_beta.powi(4) / 1920.0 * log_moneyness.powi(4));
+                let z_over_x = if z.abs() < 1e-12 { 1.0 } else { z / self.x_of_z(z) };
+                alpha / denominator * z_over_x
+            }
+            SabrVolatilityFormula::Obloj => {
+                let z = if one_minus_beta.abs() < 1e-12 {
+                    nu * log_moneyness / alpha
+                } else {
+                    nu * (forward.powf(one_minus_beta) - strike.powf(one_minus_beta)) / (alpha * one_minus_beta)
+                };
+                if z.abs() < 1e-12 {
+                    alpha / fk_mid
+                } else {
+                    nu * log_moneyness / self.x_of_z(z)
+                }
+            }
+        };
+
+        leading * correction
+    }
+
+    // Black implied volatility of this model's strike
+    pub fn implied_volatility(&self) -> f64 {
+        self.volatility_at(self.forward(), self.strike)
+    }
+
+    // Black-Scholes model carrying the SABR implied volatility; with a spot underlying and
+    // rate r this is equivalent to Black-76 on the forward F = S e^(rT)
+    fn black_scholes_model(&self) -> BlackScholesModel {
+        BlackScholesModel {
+            underlying: self.underlying,
+            strike: self.strike,
+            maturity: self.maturity,
+            volatility: self.implied_volatility(),
+            risk_free_rate: self.risk_free_rate,
+        }
+    }
+
+    // Partial derivatives of the implied volatility with respect to F and α
+    fn volatility_sensitivities(&self) -> (f64, f64) {
+        let forward = self.forward();
+        let h_forward = Self::BUMP * forward;
+        let d_sigma_d_forward = (self.volatility_at(forward + h_forward, self.strike)
+            - self.volatility_at(forward - h_forward, self.strike))
+            / (2.0 * h_forward);
+
+        let h_alpha = Self::BUMP * self.alpha;
+        let mut model_up = self.clone();
+        model_up.alpha += h_alpha;
+        let mut model_down = self.clone();
+        model_down.alpha -= h_alpha;
+        let d_sigma_d_alpha = (model_up.volatility_at(forward, self.strike)
+            - model_down.volatility_at(forward, self.strike))
+            / (2.0 * h_alpha);
+
+        (d_sigma_d_forward, d_sigma_d_alpha)
+    }
+
+    // Fit α, ρ and ν to a slice of market implied volatilities, keeping β fixed.
+    // The current parameters are used as the starting point.
+    pub fn calibrate(&self, strikes: &[f64], market_volatilities: &[f64]) -> SabrModel {
+        assert_eq!(strikes.len(), market_volatilities.len(), "Each strike needs a market volatility");
+
+        // Work in unconstrained coordinates: α = e^a, ρ = tanh(b), ν = e^c
+        let to_model = |x: &[f64; 3]| {
+            let mut model = self.clone();
+            model.alpha = x[0].exp();
+            model.correlation = x[1].tanh();
+            model.vol_of_vol = x[2].exp();
+            model
+        };
+
+        let forward = self.forward();
+        let objective = |x: &[f64; 3]| {
+            let model = to_model(x);
+            strikes
+                .iter()
+                .zip(market_volatilities)
+                .map(|(&strike, &market)| (model.volatility_at(forward, strike) - market).powi(2))
+                .sum::<f64>()
+        };
+
+        let start = [self.alpha.ln(), self.correlation.clamp(-0.99, 0.99).atanh(), self.vol_of_vol.ln()];
+        to_model(&Self::nelder_mead(objective, start))
+    }
+
+    // Minimise a function of three variables with the Nelder-Mead simplex method
+    fn nelder_mead<F>(f: F, start: [f64; 3]) -> [f64; 3]
+    where
+        F: Fn(&[f64; 3]) -> f64,
+    {
+        let mut simplex: Vec<([f64; 3], f64)> = (0..=3)
+            .map(|i| {
+                let mut point = start;
+                if i > 0 {
+                    point[i - 1] += 0.1;
+                }
+                (point, f(&point))
+            })
+            .collect();
+
+        let combine = |a: &[f64; 3], b: &[f64; 3], t: f64| -> [f64; 3] {
+            [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1]), a[2] + t * (b[2] - a[2])]
+        };
+
+        for _ in 0..Self::MAX_ITERATIONS {
+            simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+            if simplex[3].1 - simplex[0].1 < Self::TOLERANCE {
+                break;
+            }
+
+            // Centroid of all points except the worst
+            let mut centroid = [0.0; 3];
+            for (point, _) in &simplex[..3] {
+                for k in 0..3 {
+                    centroid[k] += point[k] / 3.0;
+                }
+            }
+
+            let worst = simplex[3];
+            let reflected = combine(&centroid, &worst.0, -1.0);
+            let f_reflected = f(&reflected);
+
+            if f_reflected < simplex[0].1 {
+                let expanded = combine(&centroid, &worst.0, -2.0);
+                let f_expanded = f(&expanded);
+                simplex[3] = if f_expanded < f_reflected { (expanded, f_expanded) } else { (reflected, f_reflected) };
+            } else if f_reflected < simplex[2].1 {
+                simplex[3] = (reflected, f_reflected);
+            } else {
+                let contracted = combine(&centroid, &worst.0, 0.5);
+                let f_contracted = f(&contracted);
+                if f_contracted < worst.1 {
+                    simplex[3] = (contracted, f_contracted);
+                } else {
+                    // Shrink everything towards the best point
+                    let best = simplex[0].0;
+                    for vertex in simplex.iter_mut().skip(1) {
+                        vertex.0 = combine(&best, &vertex.0, 0.5);
+                        vertex.1 = f(&vertex.0);
+                    }
+                }
+            }
+        }
+
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        simplex[0].0
+    }
+}
+
+impl OptionPricingModel for SabrModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        self.black_scholes_model().price(option_type)
+    }
+
+    // Bartlett (2006) delta: also moves α with the forward, ∂σ/∂F + (ρν / F^β) ∂σ/∂α
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let black_scholes_model = self.black_scholes_model();
+        let (d_sigma_d_forward, d_sigma_d_alpha) = self.volatility_sensitivities();
+        let forward = self.forward();
+
+        let d_sigma_d_forward_total = d_sigma_d_forward
+            + self.correlation * self.vol_of_vol / forward.powf(self.beta) * d_sigma_d_alpha;
+
+        // dF/dS = e^(rT)
+        black_scholes_model.delta(option_type)
+            + black_scholes_model.vega(option_type) * d_sigma_d_forward_total * f64::exp(self.risk_free_rate * self.maturity)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let delta_up = model_up.delta(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let delta_down = model_down.delta(option_type);
+
+        (delta_up - delta_down) / (2.0 * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    // Bartlett vega: sensitivity to α, moving the forward with it, ∂σ/∂α + (ρF^β / ν) ∂σ/∂F
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let (d_sigma_d_forward, d_sigma_d_alpha) = self.volatility_sensitivities();
+        let forward = self.forward();
+
+        let d_sigma_d_alpha_total = d_sigma_d_alpha
+            + self.correlation * forward.powf(self.beta) / self.vol_of_vol * d_sigma_d_forward;
+
+        self.black_scholes_model().vega(option_type) * d_sigma_d_alpha_total
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sabr_model(formula: SabrVolatilityFormula) -> SabrModel {
+        SabrModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            risk_free_rate: 0.05,
+            alpha: 2.0,
+            beta: 0.5,
+            correlation: -0.3,
+            vol_of_vol: 0.4,
+            formula,
+        }
+    }
+
+    #[test]
+    fn near_the_money_volatility_matches_at_the_money_limit() {
+        for formula in [SabrVolatilityFormula::Hagan, SabrVolatilityFormula::Obloj] {
+            let model = sabr_model(formula);
+            let forward = model.forward();
+            let (alpha, beta, rho, nu) = (model.alpha, model.beta, model.correlation, model.vol_of_vol);
+
+            // α / F^(1-β) with the time correction at K = F
+            let f_beta = forward.powf(1.0 - beta);
+            let expected = alpha / f_beta
+                * (1.0 + ((1.0 - beta).powi(2) / 24.0 * alpha.powi(2) / f_beta.powi(2)
+                    + rho * beta * nu * alpha / (4.0 * f_beta)
+                    + (2.0 - 3.0 * rho.powi(2)) / 24.0 * nu.powi(2)) * model.maturity);
+
+            for strike in [forward * (1.0 - 1e-6), forward, forward * (1.0 + 1e-6)] {
+                let volatility = model.volatility_at(forward, strike);
+                assert!((volatility - expected).abs() < 1e-6, "{:?} at {}: {} vs {}", formula, strike, volatility, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn calibration_recovers_model_volatilities() {
+        let model = sabr_model(SabrVolatilityFormula::Hagan);
+        let strikes = [80.0, 90.0, 100.0, 110.0, 120.0, 130.0];
+        let forward = model.forward();
+        let market_volatilities: Vec<f64> = strikes.iter().map(|&strike| model.volatility_at(forward, strike)).collect();
+
+        let start = SabrModel { alpha: 1.5, correlation: 0.0, vol_of_vol: 0.8, ..model.clone() };
+        let calibrated = start.calibrate(&strikes, &market_volatilities);
+
+        assert!((calibrated.alpha - model.alpha).abs() < 1e-3);
+        assert!((calibrated.correlation - model.correlation).abs() < 1e-2);
+        assert!((calibrated.vol_of_vol - model.vol_of_vol).abs() < 1e-2);
+    }
+}
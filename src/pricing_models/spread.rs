@@ -0,0 +1,339 @@
+use crate::{OptionPricingModel, OptionType};
+use crate::distributions::{normal_cdf, normal_pdf};
+
+// Option to exchange the second asset for the first: max(S1 - S2, 0) for a call and
+// max(S2 - S1, 0) for a put, priced with Margrabe's (1978) closed form
+#[derive(Clone)]
+pub struct ExchangeOptionModel {
+    pub underlying1: f64,
+    pub underlying2: f64,
+    pub maturity: f64,
+    pub volatility1: f64,
+    pub volatility2: f64,
+    pub dividend_yield1: f64,
+    pub dividend_yield2: f64,
+    pub correlation: f64,
+    pub risk_free_rate: f64,
+}
+
+impl ExchangeOptionModel {
+    // Volatility of the ratio S1/S2: σ² = σ1² - 2ρσ1σ2 + σ2²
+    fn ratio_volatility(&self) -> f64 {
+        f64::sqrt(
+            self.volatility1.powi(2) - 2.0 * self.correlation * self.volatility1 * self.volatility2
+                + self.volatility2.powi(2),
+        )
+    }
+
+    fn calculate_d1_d2(&self) -> (f64, f64) {
+        let sigma_sqrt_t = self.ratio_volatility() * f64::sqrt(self.maturity);
+        let d1 = (f64::ln(self.underlying1 / self.underlying2)
+            + (self.dividend_yield2 - self.dividend_yield1) * self.maturity) / sigma_sqrt_t
+            + 0.5 * sigma_sqrt_t;
+        (d1, d1 - sigma_sqrt_t)
+    }
+
+    // Dividend-discounted spot prices S_i e^(-q_i T)
+    fn discounted_underlyings(&self) -> (f64, f64) {
+        (
+            self.underlying1 * f64::exp(-self.dividend_yield1 * self.maturity),
+            self.underlying2 * f64::exp(-self.dividend_yield2 * self.maturity),
+        )
+    }
+
+    // Delta to the first (asset = 0) or second (asset = 1) underlying
+    pub fn asset_delta(&self, asset: usize, option_type: OptionType) -> f64 {
+        let (d1, d2) = self.calculate_d1_d2();
+        let (q1, q2) = (
+            f64::exp(-self.dividend_yield1 * self.maturity),
+            f64::exp(-self.dividend_yield2 * self.maturity),
+        );
+        match (asset, option_type) {
+            (0, OptionType::Call) => q1 * normal_cdf(d1),
+            (0, OptionType::Put) => -q1 * normal_cdf(-d1),
+            (_, OptionType::Call) => -q2 * normal_cdf(d2),
+            (_, OptionType::Put) => q2 * normal_cdf(-d2),
+        }
+    }
+
+    // Gamma to the first or second underlying, the same for calls and puts
+    pub fn asset_gamma(&self, asset: usize, _option_type: OptionType) -> f64 {
+        let (d1, d2) = self.calculate_d1_d2();
+        let sigma_sqrt_t = self.ratio_volatility() * f64::sqrt(self.maturity);
+        if asset == 0 {
+            f64::exp(-self.dividend_yield1 * self.maturity) * normal_pdf(d1) / (self.underlying1 * sigma_sqrt_t)
+        } else {
+            f64::exp(-self.dividend_yield2 * self.maturity) * normal_pdf(d2) / (self.underlying2 * sigma_sqrt_t)
+        }
+    }
+
+    // Sensitivity to the correlation: ∂V/∂σ ∂σ/∂ρ with ∂σ/∂ρ = -σ1σ2/σ
+    pub fn correlation_sensitivity(&self, _option_type: OptionType) -> f64 {
+        let (d1, _) = self.calculate_d1_d2();
+        let (s1, _) = self.discounted_underlyings();
+        let ratio_vega = s1 * normal_pdf(d1) * f64::sqrt(self.maturity);
+        -ratio_vega * self.volatility1 * self.volatility2 / self.ratio_volatility()
+    }
+}
+
+// Greeks from the trait are with respect to the first underlying and its volatility
+impl OptionPricingModel for ExchangeOptionModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let (d1, d2) = self.calculate_d1_d2();
+        let (s1, s2) = self.discounted_underlyings();
+        match option_type {
+            // S1 e^(-q1 T) N(d1) - S2 e^(-q2 T) N(d2)
+            OptionType::Call => s1 * normal_cdf(d1) - s2 * normal_cdf(d2),
+            OptionType::Put => s2 * normal_cdf(-d2) - s1 * normal_cdf(-d1),
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        self.asset_delta(0, option_type)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        self.asset_gamma(0, option_type)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    // ∂V/∂σ1 = S1 e^(-q1 T) n(d1) √T (σ1 - ρσ2)/σ
+    fn vega(&self, _option_type: OptionType) -> f64 {
+        let (d1, _) = self.calculate_d1_d2();
+        let (s1, _) = self.discounted_underlyings();
+        s1 * normal_pdf(d1) * f64::sqrt(self.maturity)
+            * (self.volatility1 - self.correlation * self.volatility2) / self.ratio_volatility()
+    }
+
+    // The risk-free rate cancels between the two assets
+    fn rho(&self, _option_type: OptionType) -> f64 {
+        0.0
+    }
+}
+
+// Approximation for spread options on two lognormal assets
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SpreadApproximation {
+    // Kirk (1995): treat S2 + K as lognormal
+    Kirk,
+    // Bjerksund-Stensland (2014): a lower bound that refines Kirk's formula
+    BjerksundStensland,
+}
+
+// Spread option paying max(S1 - S2 - K, 0) for a call and max(K - (S1 - S2), 0) for a put
+#[derive(Clone)]
+pub struct SpreadOptionModel {
+    pub underlying1: f64,
+    pub underlying2: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility1: f64,
+    pub volatility2: f64,
+    pub dividend_yield1: f64,
+    pub dividend_yield2: f64,
+    pub correlation: f64,
+    pub risk_free_rate: f64,
+    pub method: SpreadApproximation,
+}
+
+impl SpreadOptionModel {
+    fn forwards(&self) -> (f64, f64) {
+        (
+            self.underlying1 * f64::exp((self.risk_free_rate - self.dividend_yield1) * self.maturity),
+            self.underlying2 * f64::exp((self.risk_free_rate - self.dividend_yield2) * self.maturity),
+        )
+    }
+
+    fn call_price(&self) -> f64 {
+        let (f1, f2) = self.forwards();
+        let (sigma1, sigma2, rho, t) = (self.volatility1, self.volatility2, self.correlation, self.maturity);
+
+        // Weight of the second asset in the lognormal S2 + K: b = F2 / (F2 + K)
+        let a = f2 + self.strike;
+        let b = f2 / a;
+        let sigma = f64::sqrt(sigma1.powi(2) - 2.0 * b * rho * sigma1 * sigma2 + b.powi(2) * sigma2.powi(2));
+        let sigma_sqrt_t = sigma * f64::sqrt(t);
+        let log_ratio = f64::ln(f1 / a);
+
+        let undiscounted = match self.method {
+            SpreadApproximation::Kirk => {
+                let d1 = log_ratio / sigma_sqrt_t + 0.5 * sigma_sqrt_t;
+                let d2 = d1 - sigma_sqrt_t;
+                f1 * normal_cdf(d1) - a * normal_cdf(d2)
+            }
+            SpreadApproximation::BjerksundStensland => {
+                let d1 = (log_ratio + (0.5 * sigma1.powi(2) - b * rho * sigma1 * sigma2 + 0.5 * b.powi(2) * sigma2.powi(2)) * t)
+                    / sigma_sqrt_t;
+                let d2 = (log_ratio + (-0.5 * sigma1.powi(2) + rho * sigma1 * sigma2 + (0.5 * b.powi(2) - b) * sigma2.powi(2)) * t)
+                    / sigma_sqrt_t;
+                let d3 = (log_ratio + (-0.5 * sigma1.powi(2) + 0.5 * b.powi(2) * sigma2.powi(2)) * t) / sigma_sqrt_t;
+                f1 * normal_cdf(d1) - f2 * normal_cdf(d2) - self.strike * normal_cdf(d3)
+            }
+        };
+
+        undiscounted * f64::exp(-self.risk_free_rate * t)
+    }
+
+    fn bumped_underlying(&self, asset: usize, epsilon: f64) -> SpreadOptionModel {
+        let mut model = self.clone();
+        if asset == 0 {
+            model.underlying1 += epsilon;
+        } else {
+            model.underlying2 += epsilon;
+        }
+        model
+    }
+
+    // Delta to the first (asset = 0) or second (asset = 1) underlying
+    pub fn asset_delta(&self, asset: usize, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price_up = self.bumped_underlying(asset, epsilon).price(option_type);
+        let price_down = self.bumped_underlying(asset, -epsilon).price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    // Gamma to the first or second underlying
+    pub fn asset_gamma(&self, asset: usize, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+        let price_up = self.bumped_underlying(asset, epsilon).price(option_type);
+        let price_down = self.bumped_underlying(asset, -epsilon).price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    // Sensitivity to the correlation
+    pub fn correlation_sensitivity(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.correlation += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.correlation -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+}
+
+// Greeks from the trait are with respect to the first underlying and its volatility
+impl OptionPricingModel for SpreadOptionModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        let call = self.call_price();
+        match option_type {
+            OptionType::Call => call,
+            // Put-call parity: C - P = e^(-rT)(F1 - F2 - K)
+            OptionType::Put => {
+                let (f1, f2) = self.forwards();
+                call - (f1 - f2 - self.strike) * f64::exp(-self.risk_free_rate * self.maturity)
+            }
+        }
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        self.asset_delta(0, option_type)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        self.asset_gamma(0, option_type)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility1 += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exchange_model() -> ExchangeOptionModel {
+        ExchangeOptionModel {
+            underlying1: 105.0,
+            underlying2: 100.0,
+            maturity: 0.75,
+            volatility1: 0.25,
+            volatility2: 0.3,
+            dividend_yield1: 0.02,
+            dividend_yield2: 0.04,
+            correlation: 0.4,
+            risk_free_rate: 0.05,
+        }
+    }
+
+    // With a zero strike both approximations collapse to Margrabe's exchange option formula
+    #[test]
+    fn zero_strike_spreads_match_margrabe() {
+        let exchange = exchange_model();
+        for method in [SpreadApproximation::Kirk, SpreadApproximation::BjerksundStensland] {
+            let spread = SpreadOptionModel {
+                underlying1: exchange.underlying1,
+                underlying2: exchange.underlying2,
+                strike: 0.0,
+                maturity: exchange.maturity,
+                volatility1: exchange.volatility1,
+                volatility2: exchange.volatility2,
+                dividend_yield1: exchange.dividend_yield1,
+                dividend_yield2: exchange.dividend_yield2,
+                correlation: exchange.correlation,
+                risk_free_rate: exchange.risk_free_rate,
+                method,
+            };
+
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let (price, expected) = (spread.price(option_type), exchange.price(option_type));
+                assert!((price - expected).abs() < 1e-10, "{:?} {:?}: {} vs {}", method, option_type, price, expected);
+
+                for asset in [0, 1] {
+                    let (delta, expected) = (spread.asset_delta(asset, option_type), exchange.asset_delta(asset, option_type));
+                    assert!((delta - expected).abs() < 1e-4, "{:?} {:?} delta {}: {} vs {}", method, option_type, asset, delta, expected);
+                }
+
+                let (sensitivity, expected) =
+                    (spread.correlation_sensitivity(option_type), exchange.correlation_sensitivity(option_type));
+                assert!((sensitivity - expected).abs() < 1e-3, "{:?} {:?}: {} vs {}", method, option_type, sensitivity, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{OptionPricingModel, OptionType, ExerciseStyle, Payoff};
+use crate::pricing_models::lattice::{payoff_value, node_value, CriticalPrice};
+use crate::pricing_models::barrier::{ParisianBarrier, BarrierKnock};
+
+// Choice of branch probabilities for the trinomial tree, both with u = e^(λσ√Δt)
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TrinomialTree {
+    // Boyle (1988): match the exact mean and variance of the lognormal one-step return
+    Boyle,
+    // Kamrad-Ritchken (1991): match the moments of the log return, pm = 1 - 1/λ²
+    KamradRitchken,
+}
+
+#[derive(Clone)]
+pub struct TrinomialModel {
+    pub underlying: f64,
+    pub strike: f64,
+    pub maturity: f64,
+    pub volatility: f64,
+    pub risk_free_rate: f64,
+    pub steps: u32,
+    pub stretch: f64, // λ: Spacing of the nodes in units of σ√Δt, at least 1; λ = √(3/2) is a common choice
+    pub tree: TrinomialTree,
+    pub exercise: ExerciseStyle,
+    pub payoff: Payoff,
+}
+
+impl TrinomialModel {
+    // Up factor and the up, middle and down probabilities
+    fn parameters(&self) -> (f64, f64, f64, f64) {
+        // Below λ = 1 the middle probability goes negative
+        assert!(self.stretch >= 1.0, "The trinomial stretch must be at least 1, got {}", self.stretch);
+        let dt = self.maturity / self.steps as f64;
+        let lambda = self.stretch;
+        let u = f64::exp(lambda * self.volatility * f64::sqrt(dt)); // Up factor: u = e^(λσ√Δt)
+
+        let (pu, pd) = match self.tree {
+            TrinomialTree::Boyle => {
+                // Mean M = e^(rΔt) and variance V = M²(e^(σ²Δt) - 1) of the one-step growth
+                let m = f64::exp(self.risk_free_rate * dt);
+                let v = m * m * (f64::exp(self.volatility.powi(2) * dt) - 1.0);
+                let denominator = (u - 1.0) * (u * u - 1.0);
+                (
+                    ((v + m * m - m) * u - (m - 1.0)) / denominator,
+                    ((v + m * m - m) * u * u - (m - 1.0) * u.powi(3)) / denominator,
+                )
+            }
+            TrinomialTree::KamradRitchken => {
+                let drift = (self.risk_free_rate - 0.5 * self.volatility.powi(2)) * f64::sqrt(dt)
+                    / (2.0 * lambda * self.volatility);
+                (
+                    1.0 / (2.0 * lambda * lambda) + drift,
+                    1.0 / (2.0 * lambda * lambda) - drift,
+                )
+            }
+        };
+
+        (u, pu, 1.0 - pu - pd, pd)
+    }
+
+    // Backward induction through the tree, returning the price and the exercise boundary
+    fn build_tree(&self, option_type: OptionType) -> (f64, Vec<(f64, f64)>) {
+        let dt = self.maturity / self.steps as f64; // Δt: Time step size
+        let (u, pu, pm, pd) = self.parameters();
+        let discount = f64::exp(-self.risk_free_rate * dt);
+        let n = self.steps as i32;
+
+        // Node j in 0..=2N at maturity has asset price S * u^(j - N)
+        let mut option_values: Vec<f64> = (0..=2 * n)
+            .map(|j| payoff_value(self.payoff, self.strike, self.underlying * u.powi(j - n), option_type))
+            .collect();
+
+        let mut exercise_boundary = Vec::new();
+        let mut critical_price = CriticalPrice::new(self.payoff, self.strike, option_type, self.exercise);
+
+        // Traverse backward through the tree; step k has nodes 0..=2k
+        for step in (0..n).rev() {
+            for j in 0..=2 * step {
+                let k = j as usize;
+                let continuation = (pu * option_values[k + 2] + pm * option_values[k + 1] + pd * option_values[k])
+                    * discount;
+
+                let asset_price = self.underlying * u.powi(j - step);
+                option_values[k] = node_value(continuation, self.payoff, self.strike, asset_price, option_type, self.exercise);
+                critical_price.record(option_values[k], asset_price);
+            }
+            critical_price.push_step(&mut exercise_boundary, step as f64 * dt);
+        }
+
+        // Boundary was collected backwards in time
+        exercise_boundary.reverse();
+
+        (option_values[0], exercise_boundary)
+    }
+
+    // Critical asset price at each time step below (put) or above (call) which early exercise
+    // is optimal, as (time, price) pairs. Empty for European options.
+    pub fn exercise_boundary(&self, option_type: OptionType) -> Vec<(f64, f64)> {
+        self.build_tree(option_type).1
+    }
+
+    // Price of an option with a Parisian or double barrier monitored at each step of the tree.
+    // Each node carries one value for every number of steps the current excursion has spent
+    // outside the corridor, and knocked-in nodes continue on the vanilla tree. The price depends on
+    // where the barrier falls between two layers of nodes; choosing `stretch` to put it halfway
+    // between them removes most of the error.
+    pub fn barrier_price(&self, barrier: &ParisianBarrier, option_type: OptionType) -> f64 {
+        let dt = self.maturity / self.steps as f64; // Δt: Time step size
+        let (u, pu, pm, pd) = self.parameters();
+        let discount = f64::exp(-self.risk_free_rate * dt);
+        let n = self.steps as i32;
+        let trigger_count = barrier.trigger_count(dt);
+        let knock_in = barrier.knock == BarrierKnock::In;
+
+        let asset_price = |step: i32, j: usize| self.underlying * u.powi(j as i32 - step);
+        let payoffs: Vec<f64> = (0..=2 * n as usize).map(|j| payoff_value(self.payoff, self.strike, asset_price(n, j), option_type)).collect();
+
+        // Values once the barrier has triggered: zero for a knock-out, the vanilla option for a knock-in
+        let mut triggered = if knock_in { payoffs.clone() } else { vec![0.0; payoffs.len()] };
+        // alive[m][j]: value at node j before the barrier triggers, m steps into the current excursion
+        let mut alive = vec![if knock_in { vec![0.0; payoffs.len()] } else { payoffs }; trigger_count];
+
+        for step in (0..n).rev() {
+            // Value at node k of the next step, reached from an excursion m steps long
+            let next_value = |m: usize, k: usize| {
+                if !barrier.is_outside(asset_price(step + 1, k)) {
+                    alive[0][k]
+                } else if m + 1 < trigger_count {
+                    alive[m + 1][k]
+                } else {
+                    triggered[k]
+                }
+            };
+
+            // A knock-in cannot be exercised before it has triggered
+            let exercise = if knock_in { ExerciseStyle::European } else { self.exercise };
+            let next_alive: Vec<Vec<f64>> = (0..trigger_count)
+                .map(|m| {
+                    (0..=2 * step as usize)
+                        .map(|j| {
+                            let continuation = (pu * next_value(m, j + 2) + pm * next_value(m, j + 1) + pd * next_value(m, j))
+                                * discount;
+                            node_value(continuation, self.payoff, self.strike, asset_price(step, j), option_type, exercise)
+                        })
+                        .collect()
+                })
+                .collect();
+
+            if knock_in {
+                triggered = (0..=2 * step as usize)
+                    .map(|j| {
+                        let continuation = (pu * triggered[j + 2] + pm * triggered[j + 1] + pd * triggered[j]) * discount;
+                        node_value(continuation, self.payoff, self.strike, asset_price(step, j), option_type, self.exercise)
+                    })
+                    .collect();
+            }
+            alive = next_alive;
+        }
+
+        alive[0][0]
+    }
+}
+
+impl OptionPricingModel for TrinomialModel {
+    fn price(&self, option_type: OptionType) -> f64 {
+        self.build_tree(option_type).0
+    }
+
+    fn delta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - price_down) / (2.0 * epsilon)
+    }
+
+    fn gamma(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.underlying += epsilon;
+        let price_up = model_up.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.underlying -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_up - 2.0 * price + price_down) / (epsilon * epsilon)
+    }
+
+    fn theta(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_down = self.clone();
+        model_down.maturity -= epsilon;
+        let price_down = model_down.price(option_type);
+
+        (price_down - price) / epsilon
+    }
+
+    fn vega(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01;
+        let price = self.price(option_type);
+
+        let mut model_up = self.clone();
+        model_up.volatility += epsilon;
+        let price_up = model_up.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+
+    fn rho(&self, option_type: OptionType) -> f64 {
+        let epsilon = 0.01; // Small change in the risk-free rate
+        let mut model_up = self.clone();
+        model_up.risk_free_rate += epsilon; // Increment the risk-free rate
+        let price_up = model_up.price(option_type);
+        let price = self.price(option_type);
+
+        (price_up - price) / epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DoubleBarrierModel, PowerOptionModel, GapOptionModel};
+
+    fn trinomial_model(stretch: f64) -> TrinomialModel {
+        TrinomialModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+            steps: 500,
+            stretch,
+            tree: TrinomialTree::KamradRitchken,
+            exercise: ExerciseStyle::European,
+            payoff: Payoff::Vanilla,
+        }
+    }
+
+    #[test]
+    fn knock_in_and_knock_out_sum_to_vanilla() {
+        let model = TrinomialModel { steps: 200, ..trinomial_model(f64::sqrt(1.5)) };
+        for window in [0.0, 0.05] {
+            let knock_out = ParisianBarrier { lower_barrier: 80.0, upper_barrier: 130.0, window, knock: BarrierKnock::Out };
+            let knock_in = ParisianBarrier { knock: BarrierKnock::In, ..knock_out };
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let total = model.barrier_price(&knock_out, option_type) + model.barrier_price(&knock_in, option_type);
+                assert!((total - model.price(option_type)).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn up_and_out_call_matches_shifted_continuous_barrier() {
+        // Barrier halfway between two layers of nodes: 130 = 100 e^(19.5 λσ√Δt)
+        let steps = 500.0;
+        let stretch = f64::ln(1.3) / (19.5 * 0.25 * f64::sqrt(1.0 / steps));
+        let barrier = ParisianBarrier { lower_barrier: 0.0, upper_barrier: 130.0, window: 0.0, knock: BarrierKnock::Out };
+        let price = trinomial_model(stretch).barrier_price(&barrier, OptionType::Call);
+
+        // Broadie-Glasserman-Kou: monitoring at each step shifts the barrier out by e^(0.5826σ√Δt)
+        let continuous = DoubleBarrierModel {
+            underlying: 100.0,
+            strike: 100.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+            lower_barrier: 1e-6,
+            upper_barrier: 130.0 * f64::exp(0.5826 * 0.25 * f64::sqrt(1.0 / steps)),
+            knock: BarrierKnock::Out,
+        };
+        let expected = continuous.price(OptionType::Call);
+        assert!((price - expected).abs() < 0.01, "{} vs {}", price, expected);
+    }
+
+    #[test]
+    fn power_and_gap_payoffs_match_closed_forms() {
+        let power = PowerOptionModel {
+            underlying: 100.0,
+            strike: 10_000.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+            exponent: 2.0,
+        };
+        let gap = GapOptionModel {
+            underlying: 100.0,
+            strike: 100.0,
+            trigger_strike: 105.0,
+            maturity: 1.0,
+            volatility: 0.25,
+            risk_free_rate: 0.05,
+            dividend_yield: 0.0,
+        };
+        let power_tree = TrinomialModel { strike: 10_000.0, payoff: Payoff::Power { exponent: 2.0 }, ..trinomial_model(f64::sqrt(1.5)) };
+        let gap_tree = TrinomialModel { payoff: Payoff::Gap { trigger_strike: 105.0 }, ..trinomial_model(f64::sqrt(1.5)) };
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let (price, expected) = (power_tree.price(option_type), power.price(option_type));
+            assert!((price - expected).abs() < 0.001 * expected.max(100.0), "power {:?}: {} vs {}", option_type, price, expected);
+
+            let (price, expected) = (gap_tree.price(option_type), gap.price(option_type));
+            assert!((price - expected).abs() < 0.05, "gap {:?}: {} vs {}", option_type, price, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "stretch must be at least 1")]
+    fn stretch_below_one_is_rejected() {
+        trinomial_model(0.9).price(OptionType::Call);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{OptionPricingModel, OptionType, BlackScholesModel};
+
+// Variance and volatility swaps struck today, priced by static replication (Demeterfi, Derman,
+// Kamal and Zou 1999). The log payoff is replicated piecewise linearly with out-of-the-money puts
+// below the boundary strike S* and calls above it, each priced with Black-Scholes at its own
+// implied volatility, so a skewed smile can be used.
+#[derive(Clone)]
+pub struct VarianceSwapModel {
+    pub underlying: f64,
+    pub maturity: f64,
+    pub risk_free_rate: f64,
+    pub dividend_yield: f64,
+    pub strikes: Vec<f64>,      // Increasing strikes of the option strip
+    pub volatilities: Vec<f64>, // Implied volatility at each strike
+}
+
+impl VarianceSwapModel {
+    fn forward(&self) -> f64 {
+        self.underlying * f64::exp((self.risk_free_rate - self.dividend_yield) * self.maturity)
+    }
+
+    // Black-Scholes price of the strip option at `index`, on the dividend-adjusted spot
+    fn strip_price(&self, index: usize, option_type: OptionType) -> f64 {
+        BlackScholesModel {
+            underlying: self.underlying * f64::exp(-self.dividend_yield * self.maturity),
+            strike: self.strikes[index],
+            maturity: self.maturity,
+            volatility: self.volatilities[index],
+            risk_free_rate: self.risk_free_rate,
+        }.price(option_type)
+    }
+
+    // Payoff replicated by the strip: f(S) = (2/T) ((S - S*)/S* - ln(S/S*)), which is zero with
+    // zero slope at S*
+    fn replicated_payoff(&self, asset_price: f64, boundary: f64) -> f64 {
+        2.0 / self.maturity * ((asset_price - boundary) / boundary - f64::ln(asset_price / boundary))
+    }
+
+    // Fair variance strike K_var in variance units (σ², not σ)
+    pub fn fair_variance_strike(&self) -> f64 {
+        let t = self.maturity;
+        let forward = self.forward();
+
+        // Boundary S*: the strike nearest the forward
+        let boundary_index = (0..self.strikes.len())
+            .min_by(|&i, &j| (self.strikes[i] - forward).abs().total_cmp(&(self.strikes[j] - forward).abs()))
+            .unwrap();
+        let boundary = self.strikes[boundary_index];
+
+        // Calls from S* upwards and puts from S* downwards. The weight at each strike is the change
+        // in slope of the piecewise linear payoff there, with the sign flipped for puts.
+        let calls: Vec<usize> = (boundary_index..self.strikes.len()).collect();
+        let puts: Vec<usize> = (0..=boundary_index).rev().collect();
+        let mut portfolio = 0.0;
+        for (indices, option_type, sign) in [(calls, OptionType::Call, 1.0), (puts, OptionType::Put, -1.0)] {
+            let mut previous_slope = 0.0;
+            for pair in indices.windows(2) {
+                let (k0, k1) = (self.strikes[pair[0]], self.strikes[pair[1]]);
+                let slope = (self.replicated_payoff(k1, boundary) - self.replicated_payoff(k0, boundary)) / (k1 - k0);
+                portfolio += sign * (slope - previous_slope) * self.strip_price(pair[0], option_type);
+                previous_slope = slope;
+            }
+        }
+
+        // K_var = (2/T) ((r - q)T - (F/S* - 1) - ln(S*/S0)) + e^(rT) Π
+        2.0 / t * ((self.risk_free_rate - self.dividend_yield) * t - (forward / boundary - 1.0)
+            - f64::ln(boundary / self.underlying))
+            + f64::exp(self.risk_free_rate * t) * portfolio
+    }
+
+    // Fair volatility strike with the Brockhaus-Long (2000) convexity adjustment
+    // E[σ_R] ≈ √K_var - Var(σ_R²) / (8 K_var^(3/2)), given the variance of the realised variance
+    // from a dynamic model, e.g. `MonteCarloModel::realized_variance_moments`
+    pub fn fair_volatility_strike(&self, variance_of_variance: f64) -> f64 {
+        let variance_strike = self.fair_variance_strike();
+        variance_strike.sqrt() - variance_of_variance / (8.0 * variance_strike.powf(1.5))
+    }
+
+    // Value of a variance swap paying notional × (σ_R² - strike) at maturity
+    pub fn variance_swap_value(&self, variance_strike: f64, notional: f64) -> f64 {
+        notional * (self.fair_variance_strike() - variance_strike) * f64::exp(-self.risk_free_rate * self.maturity)
+    }
+
+    // Value of a volatility swap paying notional × (σ_R - strike) at maturity
+    pub fn volatility_swap_value(&self, volatility_strike: f64, notional: f64, variance_of_variance: f64) -> f64 {
+        notional * (self.fair_volatility_strike(variance_of_variance) - volatility_strike)
+            * f64::exp(-self.risk_free_rate * self.maturity)
+    }
+}